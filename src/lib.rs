@@ -2,27 +2,144 @@
 //! This is useful for strongly typing composable sets of strings.
 //! The crate is `no_std` compatible and uses `alloc` by default.
 //!
+//! ## Generated methods
+//!
+//! Every derive implements Display and FromStr, and generates:
+//!
+//! - `as_str()` and `as_bytes()`: Get the display string of a variant of an all-unit enum, as `&'static str` or
+//!   UTF-8 bytes.
+//! - `from_bytes(value)` and `TryFrom<&[u8]>`: Parse a variant from the UTF-8 bytes of one of its display
+//!   strings, without validating them first. FromStr parses the same way.
+//! - `parse_prefix(input)`: Parse the variant whose display string is the longest prefix of `input`, returning it
+//!   with the rest of the input.
+//! - `complete(prefix)`, `complete_with(prefix, case_insensitive)` and `complete_described(...)`: Get the sorted
+//!   display strings of unit variants starting with `prefix`, the last along with the variants' doc comments.
+//! - `template()` and `arguments()`: Get the format string a variant is displayed with and, with `alloc`, the
+//!   displayed values of its fields.
+//! - `VARIANT_NAMES`, `variant_names()`, `variant_name()` and `variant_index()`: Get the declared variant names,
+//!   and a variant's name and position in declaration order.
+//! - `suggestions(input)` and `parse_list(s, separator)`: With `alloc`, get the accepted strings within a few edits
+//!   of `input`, closest first, and parse a list of display strings separated by `separator`.
+//!
+//! The options below generate more.
+//!
 //! ## Features
 //!
-//! - `try_from_string` (optional): Enables `TryFrom<String>` implementation for enums with only unit variants.
-//!   This feature is not enabled by default and requires `std`. To enable it, use:
+//! - `alloc` (default): Enables the generated methods that return `String` or `Vec` and the `gettext_pot!`
+//!   macro rendering a `.pot` catalog from enums with the `gettext` option, and includes the invalid
+//!   input, the accepted strings and the closest match in FromStr errors. Without it, the derive emits no
+//!   references to `alloc`: FromStr fails with a `&'static str`, `template()` returns `&'static str` and
+//!   `variant_names()` returns a static slice.
+//! - `try_from_string` (optional): Enables `TryFrom<&str>`, and with `alloc` also `TryFrom<String>` and `TryFrom<Cow<str>>`,
+//!   implementations that defer to FromStr and reject strings shared by several variants.
+//!   This feature is not enabled by default. To enable it, use:
 //!   ```toml
-//!   enum2str = { version = "0.1.16", features = ["try_from_string"] }
+//!   enum2str = { version = "0.2.0", features = ["try_from_string"] }
 //!   ```
+//! - `rusqlite` (optional): Implements `rusqlite`'s `ToSql` and `FromSql` using the Display and FromStr impls.
+//!   The deriving crate must depend on `rusqlite` itself.
+//! - `log` (optional): Implements `log::kv::ToValue`, recording unit variants as `&'static str`, and `log::kv::Source`,
+//!   recording a variant's template and each of its arguments as separate key-values.
+//!   The deriving crate must depend on `log` with its `kv` feature enabled.
+//! - `tracing` (optional): Generates `tracing_value()` for use as a `tracing` field value, and `record_tracing(span)`,
+//!   recording the template and each argument as separate fields the span declares.
+//!   `tracing::Value` is sealed, so it cannot be implemented for the enum directly.
+//!   The deriving crate must depend on `tracing` itself.
+//! - `fluent` (optional): Generates `fluent_id()`, `fluent_args()`, `localize(&bundle)` and a default `.ftl`
+//!   skeleton from `fluent_source()`. Message ids join the kebab-case enum and variant names, and positional
+//!   arguments are passed as `$arg0`, `$arg1`, ... Requires `alloc`, and the deriving crate must depend on
+//!   `fluent-bundle` itself.
+//!
+//! ## Enum options
+//!
+//! Options given to the enum itself with `#[enum2str(...)]`:
+//!
+//! - `ffi`: Generates an `extern "C"` function `<enum>_as_cstr` returning the display string of a discriminant.
+//!   Requires only unit variants and a `#[repr(C)]` or integer `#[repr(...)]`.
+//! - `typescript`: Generates `typescript_definition()`, a TypeScript string union of the display strings with
+//!   template literal types for placeholders, and `typescript_const_definition()`, which adds a const object of
+//!   the fixed strings.
+//! - `graphql`: Generates `graphql_sdl()`, a GraphQL enum type of the unit variants with descriptions from doc
+//!   comments and `@deprecated` directives, and `graphql_values()`, pairing each GraphQL value with its display
+//!   string. Values are the display strings in ASCII `SCREAMING_SNAKE_CASE`, and a value already taken by an
+//!   earlier variant gets a numeric suffix such as `_2`.
+//! - `sql`: Generates `sql_check_constraint(column)` and `postgres_create_type(name)`, restricting a column or
+//!   a PostgreSQL enum type to the distinct strings FromStr accepts. Requires `alloc`.
+//! - `cstr`: Generates `from_cstr(&CStr)`, which parses like FromStr, and for an all-unit enum `as_cstr()`,
+//!   returning the display string as a `&'static CStr`. Display strings must not contain NUL bytes.
+//! - `find`: Generates `find_all(haystack)`, an iterator over the leftmost-longest non-overlapping occurrences
+//!   of unit variants' display strings with their byte ranges, and `find_all_with(haystack, word_boundary,
+//!   case_insensitive)`. The strings are matched with one automaton built at compile time.
+//! - `gettext`: Generates `gettext_entries()`, pairing each variant's `Enum::Variant` context with its template,
+//!   for extracting translatable strings. `gettext_pot!(Enum, ...)` renders the entries of such enums as a
+//!   `.pot` catalog.
+//! - `table`: Stores the display strings of an all-unit enum in one static table that Display, `as_str()`,
+//!   parsing, `parse_prefix()`, completions, suggestions and the other generated methods index, instead of
+//!   emitting a match or a copy of the strings per method. This keeps the expansion small for enums with
+//!   hundreds of variants. The enum must implement `Copy`.
+//! - `set`: Generates a `{Name}Set` bitset type for an all-unit enum, with set operations, iteration in
+//!   declaration order, and Display and FromStr impls joining the display strings with a separator.
+//!   The separator defaults to `,` and is chosen with `separator = "|"`. Display strings must be non-empty,
+//!   without surrounding whitespace and must not contain the separator.
+//! - `info`: Generates a `VARIANT_INFO` table of `{Name}VariantInfo` values describing each variant's name,
+//!   template, field kind and names, attribute paths and doc comment.
+//! - `numeric`: Lets FromStr accept the discriminant of an all-unit enum as a decimal string, or its
+//!   declaration index when no variant has an explicit discriminant.
+//! - `ordinal`: Generates `from_index(index)` and `index()` for an all-unit enum, converting between variants and
+//!   their positions in declaration order, and `from_discriminant(value)` and `discriminant()` when it declares
+//!   explicit discriminants.
+//! - `iter`: Generates `iter()` over the variants of an all-unit enum in declaration order, and `next()` and
+//!   `prev()` with their `next_wrapping()` and `prev_wrapping()` forms.
+//! - `kind = "ObjectKind"`: Generates a fieldless twin enum with the same variants and a `kind()` method
+//!   returning a variant's twin. The twin derives `Clone`, `Copy`, `Eq` and `Hash` and has its own enum2str
+//!   methods, including the `iter` ones. Unit variants keep their display strings, and data variants display as their names.
+//! - `props(required = "key, ...")`: Requires every variant to declare the listed props. The keys may also
+//!   be given as `props(required(key, ...))`.
+//! - `fluent_prefix = "app-color"`: Replaces the kebab-case enum name at the start of Fluent message ids.
+//!   Requires the `fluent` feature.
+//! - `reprs(ui, wire, ...)`: Lists named string representations that the variants of an all-unit enum declare with
+//!   `repr(...)`. Generates a `{Name}Repr` enum, `to_str_as`, `from_str_as` and the per-representation
+//!   `repr_strings` tables. Add `default_repr = "ui"` to use a representation as the display string.
+//!   Each name becomes a camel-cased `{Name}Repr` variant, so names must camel-case to distinct identifiers.
+//!
+//! ## Variant options
+//!
+//! Options given to a variant with `#[enum2str(...)]`, alongside or instead of its display string:
+//!
+//! - `skip`: Leaves the variant out of `complete()` and the other completion helpers, and out of `iter()`,
+//!   `next()` and `prev()` with their wrapping forms.
+//! - `prop(key = value, ...)`: Attaches static string, integer, float or boolean props to the variant, read
+//!   with `get_prop("key")` as a `{Name}PropValue` or with the typed `const fn prop_key()`. The accessor
+//!   returns an `Option` unless the prop is required. A key must hold the same type on every variant.
+//! - `group = "name"`: Adds the variant to a group, and may be repeated. Enables `groups()`, `in_group("name")`
+//!   and, for all-unit enums, `variants_in_group("name")`.
+//! - `repr(wire = "RED", ...)`: Gives the variant strings in the representations listed with `reprs(...)`.
+//!   A representation the variant leaves out falls back to its display string.
+//! - `locale(de = "Eigene Farbe", ...)`: Gives the variant templates in other locales, written with
+//!   `display_in("de")`. A locale the variant leaves out falls back to its display string. Every locale must
+//!   use the same placeholders as the variant's own template.
 //!
 //! ## Usage
 //!
 //! Add this to your `Cargo.toml`:
 //!
 //! ```toml
-//! enum2str = "0.1.16"
+//! enum2str = "0.2.0"
 //! ```
 //!
+//! The minimum supported Rust version is 1.82.
+//!
 //! For `no_std` environments, make sure you have `alloc` available:
 //!
 //! ```rust
 //! extern crate alloc;
 //! ```
+//!
+//! Or, without a global allocator, disable the default features:
+//!
+//! ```toml
+//! enum2str = { version = "0.2.0", default-features = false }
+//! ```
 
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
@@ -40,124 +157,3174 @@ macro_rules! derive_error {
     };
 }
 
-#[cfg(feature = "try_from_string")]
-fn has_only_unit_variants(data: &syn::DataEnum) -> bool {
-    data.variants
-        .iter()
-        .all(|variant| matches!(variant.fields, Fields::Unit))
+fn has_only_unit_variants(data: &syn::DataEnum) -> bool {
+    data.variants
+        .iter()
+        .all(|variant| matches!(variant.fields, Fields::Unit))
+}
+
+/// Finds the strings that parse to more than one variant, in declaration order
+#[cfg(feature = "try_from_string")]
+fn find_duplicate_strings(variants: &[ParsedVariant]) -> Vec<(String, Vec<String>)> {
+    let mut string_to_variants: Vec<(String, Vec<String>)> = Vec::new();
+
+    for parsed in variants {
+        if let Some(string) = parsed.parse_string() {
+            let variant_name = parsed.variant.ident.to_string();
+            match string_to_variants
+                .iter_mut()
+                .find(|(existing, _)| *existing == string)
+            {
+                Some((_, variants)) => variants.push(variant_name),
+                None => string_to_variants.push((string, vec![variant_name])),
+            }
+        }
+    }
+
+    string_to_variants
+        .into_iter()
+        .filter(|(_, variants)| variants.len() > 1)
+        .collect()
+}
+
+/// Options given with `#[enum2str(...)]` on the enum itself
+#[derive(Default)]
+struct EnumOptions {
+    /// Emit `extern "C"` functions returning the string of a discriminant
+    ffi: bool,
+    /// Generate `typescript_definition` and `typescript_const_definition`
+    typescript: bool,
+    /// Generate `graphql_sdl` and `graphql_values`
+    graphql: bool,
+    /// Generate `sql_check_constraint` and `postgres_create_type`
+    sql: bool,
+    /// Generate `as_cstr` and `from_cstr`
+    cstr: bool,
+    /// Generate `find_all` and `find_all_with`
+    find: bool,
+    /// Generate `gettext_entries`
+    gettext: bool,
+    /// Generate `from_index` and `index`, and `from_discriminant` and `discriminant` with explicit discriminants
+    ordinal: bool,
+    /// Generate `iter`, `next`, `prev`, `next_wrapping` and `prev_wrapping`
+    iter: bool,
+    /// Look up the strings of an all-unit enum in one static table instead of per-method matches
+    table: bool,
+    /// Generate a `{Name}Set` bitset type for an all-unit enum
+    set: bool,
+    /// The separator the set type is displayed and parsed with, given with `separator = "..."`
+    separator: Option<LitStr>,
+    /// Generate a `VARIANT_INFO` table describing each variant
+    info: bool,
+    /// Also parse an all-unit enum from its discriminants, or its indices without explicit discriminants
+    numeric: bool,
+    /// The name of a fieldless twin enum to generate, given with `kind = "..."`
+    kind: Option<Ident>,
+    /// The props every variant must declare, given with `props(required(...))` or `props(required = "...")`
+    required_props: Vec<Ident>,
+    /// The names of the representations variants declare, given with `reprs(...)`
+    reprs: Vec<Ident>,
+    /// The representation used as the display string, given with `default_repr = "..."`
+    default_repr: Option<Ident>,
+    /// The prefix of the Fluent message ids, given with `fluent_prefix = "..."`
+    fluent_prefix: Option<LitStr>,
+}
+
+impl EnumOptions {
+    fn parse(attrs: &[syn::Attribute]) -> Result<Self, Error> {
+        let mut options = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("enum2str")) {
+            let list = match attr.parse_meta()? {
+                syn::Meta::List(list) => list,
+                meta => {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "Expected a list of options. Example: #[enum2str(ffi)]",
+                    ));
+                }
+            };
+
+            for nested in list.nested.iter() {
+                match nested {
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("ffi") => {
+                        options.ffi = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("typescript") => {
+                        options.typescript = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("graphql") => {
+                        options.graphql = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("sql") => {
+                        if !cfg!(feature = "alloc") {
+                            return Err(Error::new_spanned(
+                                path,
+                                "#[enum2str(sql)] requires the `alloc` feature",
+                            ));
+                        }
+                        options.sql = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("cstr") => {
+                        options.cstr = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("find") => {
+                        options.find = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("gettext") => {
+                        options.gettext = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("ordinal") => {
+                        options.ordinal = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("iter") => {
+                        options.iter = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("table") => {
+                        options.table = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("set") => {
+                        options.set = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("info") => {
+                        options.info = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("numeric") => {
+                        options.numeric = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                        path,
+                        lit: syn::Lit::Str(separator),
+                        ..
+                    })) if path.is_ident("separator") => {
+                        options.separator = Some(separator.clone());
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                        path,
+                        lit: syn::Lit::Str(prefix),
+                        ..
+                    })) if path.is_ident("fluent_prefix") => {
+                        options.fluent_prefix = Some(prefix.clone());
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                        path,
+                        lit: syn::Lit::Str(kind),
+                        ..
+                    })) if path.is_ident("kind") => {
+                        options.kind = Some(kind.parse()?);
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::List(reprs))
+                        if reprs.path.is_ident("reprs") =>
+                    {
+                        for repr in reprs.nested.iter() {
+                            match repr {
+                                syn::NestedMeta::Meta(syn::Meta::Path(path))
+                                    if path.get_ident().is_some() =>
+                                {
+                                    options.reprs.extend(path.get_ident().cloned());
+                                }
+                                _ => {
+                                    return Err(Error::new_spanned(
+                                        repr,
+                                        "Expected a representation name",
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                        path,
+                        lit: syn::Lit::Str(repr),
+                        ..
+                    })) if path.is_ident("default_repr") => {
+                        options.default_repr = Some(repr.parse()?);
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::List(props))
+                        if props.path.is_ident("props") =>
+                    {
+                        for prop_option in props.nested.iter() {
+                            match prop_option {
+                                syn::NestedMeta::Meta(syn::Meta::List(required))
+                                    if required.path.is_ident("required") =>
+                                {
+                                    for key in required.nested.iter() {
+                                        match key {
+                                            syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
+                                                options.required_props.push(
+                                                    path.get_ident().cloned().ok_or_else(|| {
+                                                        Error::new_spanned(
+                                                            path,
+                                                            "Expected a prop name",
+                                                        )
+                                                    })?,
+                                                );
+                                            }
+                                            _ => {
+                                                return Err(Error::new_spanned(
+                                                    key,
+                                                    "Expected a prop name",
+                                                ));
+                                            }
+                                        }
+                                    }
+                                }
+                                syn::NestedMeta::Meta(syn::Meta::NameValue(
+                                    syn::MetaNameValue {
+                                        path,
+                                        lit: syn::Lit::Str(required),
+                                        ..
+                                    },
+                                )) if path.is_ident("required") => {
+                                    for key in required.value().split(',').map(str::trim) {
+                                        let mut key =
+                                            syn::parse_str::<Ident>(key).map_err(|_| {
+                                                Error::new_spanned(required, "Expected a prop name")
+                                            })?;
+                                        key.set_span(required.span());
+                                        options.required_props.push(key);
+                                    }
+                                }
+                                _ => {
+                                    return Err(Error::new_spanned(
+                                        prop_option,
+                                        "Unknown props option",
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                    _ => return Err(Error::new_spanned(nested, "Unknown enum2str option")),
+                }
+            }
+        }
+
+        let mut repr_variant_names: Vec<String> = Vec::new();
+        for repr in &options.reprs {
+            let variant_name = repr_variant_name(repr);
+            if syn::parse_str::<Ident>(&variant_name).is_err() {
+                return Err(Error::new_spanned(
+                    repr,
+                    "The representation name does not camel-case to a valid variant name",
+                ));
+            }
+            if repr_variant_names.contains(&variant_name) {
+                let message = format!(
+                    "The representation name camel-cases to `{}` like an earlier one",
+                    variant_name
+                );
+                return Err(Error::new_spanned(repr, message));
+            }
+            repr_variant_names.push(variant_name);
+        }
+
+        if let Some(default_repr) = &options.default_repr {
+            if !options.reprs.contains(default_repr) {
+                return Err(Error::new_spanned(
+                    default_repr,
+                    "The default representation must be listed in reprs(...)",
+                ));
+            }
+        }
+
+        if let (Some(separator), false) = (&options.separator, options.set) {
+            return Err(Error::new_spanned(
+                separator,
+                "The separator option requires #[enum2str(set)]",
+            ));
+        }
+
+        if let (Some(prefix), false) = (&options.fluent_prefix, cfg!(feature = "fluent")) {
+            return Err(Error::new_spanned(
+                prefix,
+                "The fluent_prefix option requires the `fluent` feature",
+            ));
+        }
+
+        Ok(options)
+    }
+}
+
+const MISSING_STRING_ARGUMENT: &str = r#"The 'enum2str' attribute is missing a String argument. Example: #[enum2str("Listening on: {} {}")] "#;
+
+/// A variant along with its parsed `enum2str` attribute
+struct ParsedVariant<'a> {
+    variant: &'a syn::Variant,
+    /// The string given with `#[enum2str("...")]`, if any
+    literal: Option<LitStr>,
+    /// Whether `#[enum2str(skip)]` leaves the variant out of completions and iteration
+    skip: bool,
+    /// The values given with `#[enum2str(prop(key = value, ...))]`
+    props: Vec<(Ident, syn::Lit)>,
+    /// The groups given with `#[enum2str(group = "...")]`, in order
+    groups: Vec<LitStr>,
+    /// The strings given with `#[enum2str(repr(name = "...", ...))]`
+    reprs: Vec<(Ident, LitStr)>,
+    /// The templates given with `#[enum2str(locale(name = "...", ...))]`
+    locales: Vec<(Ident, LitStr)>,
+}
+
+impl<'a> ParsedVariant<'a> {
+    fn parse(variant: &'a syn::Variant) -> Result<Self, Error> {
+        let mut parsed = Self {
+            variant,
+            literal: None,
+            skip: false,
+            props: Vec::new(),
+            groups: Vec::new(),
+            reprs: Vec::new(),
+            locales: Vec::new(),
+        };
+
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("enum2str"))
+        {
+            let list = match attr.parse_meta() {
+                Ok(syn::Meta::List(list)) => list,
+                _ => return Err(Error::new(Span::call_site(), MISSING_STRING_ARGUMENT)),
+            };
+
+            for nested in list.nested.iter() {
+                match nested {
+                    syn::NestedMeta::Lit(syn::Lit::Str(literal)) => {
+                        parsed.literal = Some(literal.clone());
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("skip") => {
+                        parsed.skip = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                        path,
+                        lit: syn::Lit::Str(group),
+                        ..
+                    })) if path.is_ident("group") => {
+                        if parsed
+                            .groups
+                            .iter()
+                            .all(|existing| existing.value() != group.value())
+                        {
+                            parsed.groups.push(group.clone());
+                        }
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::List(locales))
+                        if locales.path.is_ident("locale") =>
+                    {
+                        for locale in locales.nested.iter() {
+                            match locale {
+                                syn::NestedMeta::Meta(syn::Meta::NameValue(
+                                    syn::MetaNameValue {
+                                        path,
+                                        lit: syn::Lit::Str(template),
+                                        ..
+                                    },
+                                )) if path.get_ident().is_some() => {
+                                    parsed.locales.extend(
+                                        path.get_ident().map(|key| (key.clone(), template.clone())),
+                                    );
+                                }
+                                _ => {
+                                    return Err(Error::new_spanned(
+                                        locale,
+                                        "Expected a locale like: #[enum2str(locale(de = \"Eigene Farbe\"))]",
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::List(reprs))
+                        if reprs.path.is_ident("repr") =>
+                    {
+                        for repr in reprs.nested.iter() {
+                            match repr {
+                                syn::NestedMeta::Meta(syn::Meta::NameValue(
+                                    syn::MetaNameValue {
+                                        path,
+                                        lit: syn::Lit::Str(value),
+                                        ..
+                                    },
+                                )) if path.get_ident().is_some() => {
+                                    parsed.reprs.extend(
+                                        path.get_ident().map(|key| (key.clone(), value.clone())),
+                                    );
+                                }
+                                _ => {
+                                    return Err(Error::new_spanned(
+                                        repr,
+                                        "Expected a representation like: #[enum2str(repr(wire = \"RED\"))]",
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::List(props))
+                        if props.path.is_ident("prop") =>
+                    {
+                        for prop in props.nested.iter() {
+                            match prop {
+                                syn::NestedMeta::Meta(syn::Meta::NameValue(
+                                    syn::MetaNameValue { path, lit, .. },
+                                )) => {
+                                    let key = path.get_ident().cloned().ok_or_else(|| {
+                                        Error::new_spanned(path, "Expected a prop name")
+                                    })?;
+                                    if parsed.props.iter().any(|(existing, _)| *existing == key) {
+                                        return Err(Error::new_spanned(key, "Duplicate prop"));
+                                    }
+                                    parsed.props.push((key, lit.clone()));
+                                }
+                                _ => {
+                                    return Err(Error::new_spanned(
+                                        prop,
+                                        "Expected a prop like: #[enum2str(prop(hex = \"#800020\"))]",
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                    syn::NestedMeta::Lit(_) => {
+                        return Err(Error::new(Span::call_site(), MISSING_STRING_ARGUMENT));
+                    }
+                    _ => {
+                        return Err(Error::new_spanned(
+                            nested,
+                            "Unknown enum2str variant option",
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// The string `template()` returns for this variant
+    fn template(&self) -> String {
+        match (&self.literal, &self.variant.fields) {
+            (Some(literal), _) => literal.value(),
+            (None, Fields::Unnamed(_)) => "{}".to_string(),
+            (None, _) => self.variant.ident.to_string(),
+        }
+    }
+
+    /// Whether Display treats the template as a format string instead of writing it verbatim
+    fn is_format_string(&self) -> bool {
+        match &self.variant.fields {
+            Fields::Unit => false,
+            Fields::Unnamed(_) => true,
+            Fields::Named(_) => {
+                let template = self.template();
+                self.literal.is_some() && template.contains('{') && template.contains('}')
+            }
+        }
+    }
+
+    /// The string FromStr accepts for this variant, if it can be parsed at all
+    fn parse_string(&self) -> Option<String> {
+        match &self.variant.fields {
+            Fields::Unit => Some(self.template()),
+            Fields::Named(FieldsNamed { named, .. })
+                if named.is_empty() && !self.is_format_string() =>
+            {
+                Some(self.template())
+            }
+            _ => None,
+        }
+    }
+
+    /// The bindings and key names of the fields `arguments()` reports for this variant
+    fn argument_fields(&self) -> Vec<(Ident, String)> {
+        match &self.variant.fields {
+            Fields::Unnamed(FieldsUnnamed { unnamed, .. }) if self.template().contains("{}") => {
+                ('a'..='z')
+                    .take(unnamed.len())
+                    .enumerate()
+                    .map(|(index, letter)| {
+                        (
+                            Ident::new(&letter.to_string(), self.variant.span()),
+                            index.to_string(),
+                        )
+                    })
+                    .collect()
+            }
+            Fields::Named(FieldsNamed { named, .. }) if self.is_format_string() => named
+                .iter()
+                .filter_map(|field| field.ident.clone())
+                .map(|ident| {
+                    let key = ident.to_string();
+                    (ident, key)
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// An expression building this variant, for variants `parse_string()` accepts
+    fn constructor(&self, name: &Ident) -> TokenStream2 {
+        let variant_name = &self.variant.ident;
+        match &self.variant.fields {
+            Fields::Named(_) => quote!(#name::#variant_name {}),
+            _ => quote!(#name::#variant_name),
+        }
+    }
+
+    /// A pattern matching this variant without binding any fields
+    fn wildcard_pattern(&self, name: &Ident) -> TokenStream2 {
+        let variant_name = &self.variant.ident;
+        match &self.variant.fields {
+            Fields::Unit => quote!(#name::#variant_name),
+            Fields::Unnamed(_) => quote!(#name::#variant_name(..)),
+            Fields::Named(_) => quote!(#name::#variant_name { .. }),
+        }
+    }
+
+    /// A pattern matching this variant that binds the fields from `argument_fields()`
+    fn argument_pattern(&self, name: &Ident) -> TokenStream2 {
+        let variant_name = &self.variant.ident;
+        let bindings = self
+            .argument_fields()
+            .into_iter()
+            .map(|(binding, _)| binding)
+            .collect::<Vec<_>>();
+        match &self.variant.fields {
+            Fields::Unit => quote!(#name::#variant_name),
+            Fields::Unnamed(_) if bindings.is_empty() => quote!(#name::#variant_name(..)),
+            Fields::Unnamed(_) => quote!(#name::#variant_name(#(#bindings),*)),
+            Fields::Named(_) if bindings.is_empty() => quote!(#name::#variant_name { .. }),
+            Fields::Named(_) => quote!(#name::#variant_name { #(#bindings),* }),
+        }
+    }
+
+    /// The template split into the text and placeholders Display renders
+    fn segments(&self) -> Vec<Segment> {
+        let template = self.template();
+        if self.is_format_string() {
+            template_segments(&template)
+        } else {
+            vec![Segment::Text(template)]
+        }
+    }
+}
+
+/// A piece of a display template
+enum Segment {
+    Text(String),
+    /// A `{...}` placeholder, holding its argument: empty for `{}`, otherwise an index or a field name
+    Placeholder(String),
+}
+
+fn template_segments(template: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(character) = chars.next() {
+        match character {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                text.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                text.push('}');
+            }
+            '{' => {
+                let placeholder = chars.by_ref().take_while(|c| *c != '}').collect::<String>();
+                let argument = placeholder.split(':').next().unwrap_or_default().trim();
+                if !text.is_empty() {
+                    segments.push(Segment::Text(std::mem::take(&mut text)));
+                }
+                segments.push(Segment::Placeholder(argument.to_string()));
+            }
+            _ => text.push(character),
+        }
+    }
+
+    if !text.is_empty() || segments.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+
+    segments
+}
+
+/// The arguments a format string refers to, numbering `{}` placeholders by position
+fn placeholder_arguments(template: &str) -> std::collections::BTreeSet<String> {
+    let mut position = 0;
+    template_segments(template)
+        .into_iter()
+        .filter_map(|segment| match segment {
+            Segment::Placeholder(argument) if argument.is_empty() => {
+                position += 1;
+                Some((position - 1).to_string())
+            }
+            Segment::Placeholder(argument) => Some(argument),
+            Segment::Text(_) => None,
+        })
+        .collect()
+}
+
+/// Quotes a string with JSON-style escapes, which TypeScript and GraphQL both accept
+fn quoted_string(value: &str) -> String {
+    let mut escaped = String::from('"');
+    for character in value.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Renders a variant as a TypeScript type, using a template literal type when it has placeholders
+fn typescript_type(segments: &[Segment]) -> String {
+    match segments {
+        [Segment::Text(text)] => quoted_string(text),
+        _ => {
+            let mut rendered = String::from('`');
+            for segment in segments {
+                match segment {
+                    Segment::Text(text) => {
+                        rendered.push_str(
+                            &text
+                                .replace('\\', "\\\\")
+                                .replace('`', "\\`")
+                                .replace("${", "\\${"),
+                        );
+                    }
+                    Segment::Placeholder(_) => rendered.push_str("${string}"),
+                }
+            }
+            rendered.push('`');
+            rendered
+        }
+    }
+}
+
+fn typescript_definitions(name: &Ident, variants: &[ParsedVariant]) -> (String, String) {
+    let mut members = Vec::new();
+    let mut properties = String::new();
+
+    for parsed in variants {
+        let segments = parsed.segments();
+        let member = typescript_type(&segments);
+        if !members.contains(&member) {
+            members.push(member.clone());
+        }
+        if let [Segment::Text(_)] = segments.as_slice() {
+            properties.push_str(&format!("  {}: {},\n", parsed.variant.ident, member));
+        }
+    }
+
+    let union = if members.is_empty() {
+        "never".to_string()
+    } else {
+        members.join(" | ")
+    };
+    let definition = format!("export type {} = {};\n", name, union);
+    let const_definition = format!(
+        "export const {} = {{\n{}}} as const;\n{}",
+        name, properties, definition
+    );
+
+    (definition, const_definition)
+}
+
+/// Joins the `///` doc comment lines of an item
+fn doc_comment(attrs: &[syn::Attribute]) -> String {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Str(literal),
+                ..
+            })) => Some(literal.value().trim().to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// The note of a `#[deprecated]` attribute, if the item is deprecated
+fn deprecation(attrs: &[syn::Attribute]) -> Option<Option<String>> {
+    let attr = attrs.iter().find(|attr| attr.path.is_ident("deprecated"))?;
+    let note = match attr.parse_meta() {
+        Ok(syn::Meta::NameValue(syn::MetaNameValue {
+            lit: syn::Lit::Str(note),
+            ..
+        })) => Some(note.value()),
+        Ok(syn::Meta::List(list)) => list.nested.iter().find_map(|nested| match nested {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident("note") =>
+            {
+                match &name_value.lit {
+                    syn::Lit::Str(note) => Some(note.value()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }),
+        _ => None,
+    };
+    Some(note)
+}
+
+/// Splits a string into words at separators and case changes, e.g. `HTTPServer error` into `HTTP`, `Server`, `error`
+fn words(value: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let chars = value.chars().collect::<Vec<_>>();
+
+    for (index, character) in chars.iter().enumerate() {
+        if !character.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+
+        if character.is_uppercase() && !word.is_empty() {
+            let previous = chars[index - 1];
+            let next_is_lowercase = chars.get(index + 1).is_some_and(|c| c.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_numeric()
+                || (previous.is_uppercase() && next_is_lowercase)
+            {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+
+        word.push(*character);
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+/// Converts a display string to a GraphQL enum value name, e.g. `Custom Color` to `CUSTOM_COLOR`.
+/// Characters outside ASCII separate words, since GraphQL names only allow `[_A-Za-z][_0-9A-Za-z]*`.
+fn graphql_value_name(display: &str) -> String {
+    let ascii = display
+        .chars()
+        .map(|character| if character.is_ascii() { character } else { ' ' })
+        .collect::<String>();
+    let name = words(&ascii)
+        .iter()
+        .map(|word| word.to_uppercase())
+        .collect::<Vec<_>>()
+        .join("_");
+
+    match name.chars().next() {
+        Some(first) if !first.is_ascii_digit() => name,
+        _ => format!("_{}", name),
+    }
+}
+
+/// Renders the GraphQL enum type for the unit variants along with each value's variant index.
+/// A value name already taken by an earlier variant gets a numeric suffix, e.g. `CUSTOM_COLOR_2`.
+fn graphql_definitions(
+    name: &Ident,
+    attrs: &[syn::Attribute],
+    variants: &[ParsedVariant],
+) -> (String, Vec<(String, usize)>) {
+    let mut sdl = String::new();
+    let mut values: Vec<(String, usize)> = Vec::new();
+
+    let description = doc_comment(attrs);
+    if !description.is_empty() {
+        sdl.push_str(&format!("{}\n", quoted_string(&description)));
+    }
+    sdl.push_str(&format!("enum {} {{\n", name));
+
+    for (index, parsed) in variants.iter().enumerate() {
+        if !matches!(parsed.variant.fields, Fields::Unit) {
+            continue;
+        }
+
+        let display = parsed.template();
+        let base = graphql_value_name(&display);
+        let mut value = base.clone();
+        let mut suffix = 2;
+        while values.iter().any(|(other, _)| *other == value) {
+            value = format!("{}_{}", base, suffix);
+            suffix += 1;
+        }
+
+        let description = doc_comment(&parsed.variant.attrs);
+        if !description.is_empty() {
+            sdl.push_str(&format!("  {}\n", quoted_string(&description)));
+        }
+        sdl.push_str(&format!("  {}", value));
+        match deprecation(&parsed.variant.attrs) {
+            Some(Some(reason)) => {
+                sdl.push_str(&format!(" @deprecated(reason: {})", quoted_string(&reason)))
+            }
+            Some(None) => sdl.push_str(" @deprecated"),
+            None => {}
+        }
+        sdl.push('\n');
+
+        values.push((value, index));
+    }
+
+    sdl.push_str("}\n");
+
+    (sdl, values)
+}
+
+/// Renders the distinct parseable strings, including an empty one, as a comma separated list of SQL string literals
+fn sql_values(variants: &[ParsedVariant]) -> String {
+    let mut strings: Vec<String> = Vec::new();
+    for string in variants.iter().filter_map(ParsedVariant::parse_string) {
+        if !strings.contains(&string) {
+            strings.push(string);
+        }
+    }
+    strings
+        .iter()
+        .map(|value| format!("'{}'", value.replace('\'', "''")))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The error type of the generated parsing functions, which only carries `'static` data without `alloc`
+fn parse_error_type() -> TokenStream2 {
+    if cfg!(feature = "alloc") {
+        quote!(::alloc::string::String)
+    } else {
+        quote!(&'static str)
+    }
+}
+
+/// The indices of the first variants to declare each distinct non-empty string FromStr accepts
+fn expected_indices(variants: &[ParsedVariant]) -> Vec<usize> {
+    let mut strings: Vec<String> = Vec::new();
+    let mut indices = Vec::new();
+    for (index, parsed) in variants.iter().enumerate() {
+        if let Some(string) = parsed.parse_string() {
+            if !string.is_empty() && !strings.contains(&string) {
+                strings.push(string);
+                indices.push(index);
+            }
+        }
+    }
+    indices
+}
+
+/// The distinct non-empty strings FromStr accepts, in declaration order
+fn expected_strings(variants: &[ParsedVariant]) -> Vec<String> {
+    expected_indices(variants)
+        .into_iter()
+        .map(|index| variants[index].template())
+        .collect()
+}
+
+/// An expression for the display string of the variant at `index`, which refers to the `ENUM2STR_STRINGS` table
+/// in table mode instead of repeating the string
+fn string_expr(
+    name: &Ident,
+    variants: &[ParsedVariant],
+    index: usize,
+    table: bool,
+) -> TokenStream2 {
+    if table {
+        quote!(#name::ENUM2STR_STRINGS[#index])
+    } else {
+        let string = variants[index].template();
+        quote!(#string)
+    }
+}
+
+/// An expression building the parse error for `input`, which must deref to `str`.
+/// With `alloc`, the message lists the accepted strings and the closest one to the input.
+fn parse_error(
+    name: &Ident,
+    variants: &[ParsedVariant],
+    input: TokenStream2,
+    table: bool,
+) -> TokenStream2 {
+    if cfg!(feature = "alloc") {
+        let expected = expected_strings(variants);
+        let expected = if expected.is_empty() {
+            TokenStream2::new()
+        } else if table {
+            quote! {
+                let mut separator = "; expected one of ";
+                for index in Self::ENUM2STR_EXPECTED {
+                    message.push_str(separator);
+                    message.push_str(Self::ENUM2STR_STRINGS[*index]);
+                    separator = ", ";
+                }
+            }
+        } else {
+            let expected = format!("; expected one of {}", expected.join(", "));
+            quote!(message.push_str(#expected);)
+        };
+
+        quote! {{
+            let input = #input;
+            let mut message = ::alloc::format!("Invalid {} variant: {}", stringify!(#name), input);
+            #expected
+            if let ::core::option::Option::Some(suggestion) = Self::suggestions(&input).first() {
+                message = ::alloc::format!("{}; did you mean \"{}\"?", message, suggestion);
+            }
+            message
+        }}
+    } else {
+        let message = format!("Invalid {} variant", name);
+        quote!(#message)
+    }
+}
+
+/// An expression building a parse error from a fixed message
+#[cfg(feature = "try_from_string")]
+fn static_parse_error(message: &str) -> TokenStream2 {
+    if cfg!(feature = "alloc") {
+        quote!(::alloc::string::String::from(#message))
+    } else {
+        quote!(#message)
+    }
+}
+
+/// Generates `TryFrom` impls for string types that defer to FromStr, rejecting strings shared by several variants
+#[cfg(feature = "try_from_string")]
+fn try_from_impls(name: &Ident, variants: &[ParsedVariant]) -> TokenStream2 {
+    let error_type = parse_error_type();
+    let duplicates = find_duplicate_strings(variants);
+
+    let ambiguity_check = if duplicates.is_empty() {
+        TokenStream2::new()
+    } else {
+        let error_msg = format!(
+            "Ambiguous string representation. The following strings are used by multiple variants: {}",
+            duplicates
+                .iter()
+                .map(|(s, v)| format!("'{}' (used by {})", s, v.join(", ")))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let error = static_parse_error(&error_msg);
+        let duplicate_strings = duplicates.iter().map(|(s, _)| s);
+
+        quote! {
+            if [#(#duplicate_strings),*].contains(&value) {
+                return ::core::result::Result::Err(#error);
+            }
+        }
+    };
+
+    let mut impls = quote! {
+        impl<'a> ::core::convert::TryFrom<&'a str> for #name {
+            type Error = #error_type;
+
+            fn try_from(value: &'a str) -> ::core::result::Result<Self, #error_type> {
+                #ambiguity_check
+                <Self as ::core::str::FromStr>::from_str(value)
+            }
+        }
+    };
+
+    if cfg!(feature = "alloc") {
+        impls.extend(quote! {
+            impl ::core::convert::TryFrom<::alloc::string::String> for #name {
+                type Error = #error_type;
+
+                fn try_from(value: ::alloc::string::String) -> ::core::result::Result<Self, #error_type> {
+                    <Self as ::core::convert::TryFrom<&str>>::try_from(value.as_str())
+                }
+            }
+
+            impl<'a> ::core::convert::TryFrom<::alloc::borrow::Cow<'a, str>> for #name {
+                type Error = #error_type;
+
+                fn try_from(
+                    value: ::alloc::borrow::Cow<'a, str>,
+                ) -> ::core::result::Result<Self, #error_type> {
+                    <Self as ::core::convert::TryFrom<&str>>::try_from(value.as_ref())
+                }
+            }
+        });
+    }
+
+    impls
+}
+
+#[cfg(feature = "rusqlite")]
+fn rusqlite_impls(name: &Ident) -> TokenStream2 {
+    quote! {
+        impl ::rusqlite::types::ToSql for #name {
+            fn to_sql(&self) -> ::rusqlite::Result<::rusqlite::types::ToSqlOutput<'_>> {
+                ::core::result::Result::Ok(::rusqlite::types::ToSqlOutput::from(
+                    ::alloc::string::ToString::to_string(self),
+                ))
+            }
+        }
+
+        impl ::rusqlite::types::FromSql for #name {
+            fn column_result(
+                value: ::rusqlite::types::ValueRef<'_>,
+            ) -> ::rusqlite::types::FromSqlResult<Self> {
+                value.as_str().and_then(|s| {
+                    <Self as ::core::str::FromStr>::from_str(s)
+                        .map_err(|error| ::rusqlite::types::FromSqlError::Other(error.into()))
+                })
+            }
+        }
+    }
+}
+
+/// The integer type of a `#[repr(...)]` attribute, with `#[repr(C)]` mapping to `c_int`
+fn repr_type(attrs: &[syn::Attribute]) -> Option<TokenStream2> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .find_map(|nested| match nested {
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
+                let ident = path.get_ident()?.to_string();
+                match ident.as_str() {
+                    "C" => Some(quote!(::core::ffi::c_int)),
+                    "u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64"
+                    | "isize" => Some(path.to_token_stream()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+}
+
+/// A C string literal of a variant's display string, which must not contain NUL bytes
+fn c_string(value: &str, span: Span) -> Result<proc_macro2::Literal, Error> {
+    let value = std::ffi::CString::new(value).map_err(|_| {
+        Error::new(
+            span,
+            "The display string of a variant converted to a C string cannot contain NUL bytes",
+        )
+    })?;
+    Ok(proc_macro2::Literal::c_string(&value))
+}
+
+/// The number of parseable strings above which parsing switches from a flat match to a length and byte trie
+const DISPATCH_THRESHOLD: usize = 16;
+
+/// A parseable string and the variant it parses to
+struct Candidate {
+    bytes: Vec<u8>,
+    variant: TokenStream2,
+}
+
+/// Compares the bytes of `input` from `depth` on against each candidate in turn, so the first of several
+/// variants sharing a string wins while the others are still constructed
+fn compare_chain(candidates: &[&Candidate], depth: usize, input: &Ident) -> TokenStream2 {
+    let arms = candidates.iter().map(|candidate| {
+        let rest = syn::LitByteStr::new(&candidate.bytes[depth..], Span::call_site());
+        let variant = &candidate.variant;
+        quote!(rest if rest == #rest => ::core::option::Option::Some(#variant),)
+    });
+
+    quote! {
+        match &#input[#depth..] {
+            #(#arms)*
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+
+/// Builds an expression matching the byte slice `input` against the parseable strings, evaluating to `Option<Self>`
+fn parse_dispatch(name: &Ident, variants: &[ParsedVariant], input: &Ident) -> TokenStream2 {
+    let candidates = variants
+        .iter()
+        .filter_map(|parsed| {
+            let constructor = parsed.constructor(name);
+            parsed.parse_string().map(|string| Candidate {
+                bytes: string.into_bytes(),
+                variant: quote_spanned!(parsed.variant.span() => #constructor),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    candidate_dispatch(&candidates, input)
+}
+
+/// Builds an expression matching the byte slice `input` against the candidates, evaluating to `Option<Self>`
+fn candidate_dispatch(candidates: &[Candidate], input: &Ident) -> TokenStream2 {
+    if candidates.len() <= DISPATCH_THRESHOLD {
+        return compare_chain(&candidates.iter().collect::<Vec<_>>(), 0, input);
+    }
+
+    let mut lengths = std::collections::BTreeMap::<usize, Vec<&Candidate>>::new();
+    for candidate in candidates {
+        lengths
+            .entry(candidate.bytes.len())
+            .or_default()
+            .push(candidate);
+    }
+
+    let arms = lengths.iter().map(|(length, group)| {
+        let trie = byte_trie(group, 0, input);
+        quote!(#length => #trie,)
+    });
+
+    quote! {
+        match #input.len() {
+            #(#arms)*
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+
+/// Switches on the byte at `depth` until only one string of the group's length remains, then compares the rest
+fn byte_trie(candidates: &[&Candidate], depth: usize, input: &Ident) -> TokenStream2 {
+    if candidates
+        .iter()
+        .all(|candidate| candidate.bytes == candidates[0].bytes)
+    {
+        return compare_chain(candidates, depth, input);
+    }
+
+    let mut branches = std::collections::BTreeMap::<u8, Vec<&Candidate>>::new();
+    for candidate in candidates {
+        branches
+            .entry(candidate.bytes[depth])
+            .or_default()
+            .push(candidate);
+    }
+
+    let arms = branches.iter().map(|(byte, group)| {
+        let byte = syn::LitByte::new(*byte, Span::call_site());
+        let trie = byte_trie(group, depth + 1, input);
+        quote!(#byte => #trie,)
+    });
+
+    quote! {
+        match #input[#depth] {
+            #(#arms)*
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+
+/// Generates `as_bytes` for all-unit enums, along with `from_bytes` and `TryFrom<&[u8]>` for every enum
+fn bytes_methods(
+    name: &Ident,
+    data: &syn::DataEnum,
+    variants: &[ParsedVariant],
+    numeric: Option<TokenStream2>,
+    table: bool,
+) -> (TokenStream2, TokenStream2) {
+    let mut methods = TokenStream2::new();
+
+    if has_only_unit_variants(data) {
+        methods.extend(quote! {
+            /// Get the UTF-8 encoded display string of a variant
+            pub fn as_bytes(&self) -> &'static [u8] {
+                self.as_str().as_bytes()
+            }
+        });
+    }
+
+    let parse_error_type = parse_error_type();
+    let invalid_input_error = parse_error(
+        name,
+        variants,
+        quote!(::alloc::string::String::from_utf8_lossy(value)),
+        table,
+    );
+
+    let input = Ident::new("value", Span::call_site());
+    let mut dispatch = if table {
+        quote! {{
+            let position = Self::ENUM2STR_SORTED
+                .partition_point(|index| Self::ENUM2STR_STRINGS[*index].as_bytes() < #input);
+            Self::ENUM2STR_SORTED
+                .get(position)
+                .filter(|index| Self::ENUM2STR_STRINGS[**index].as_bytes() == #input)
+                .map(|index| Self::ENUM2STR_VARIANTS[*index])
+        }}
+    } else {
+        parse_dispatch(name, variants, &input)
+    };
+    if let Some(numeric) = numeric {
+        dispatch = quote!((#dispatch).or_else(|| #numeric));
+    }
+
+    methods.extend(quote! {
+        /// Parse a variant from the UTF-8 encoding of one of its display strings, without validating the input first
+        pub fn from_bytes(value: &[u8]) -> ::core::result::Result<Self, #parse_error_type> {
+            match #dispatch {
+                ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                ::core::option::Option::None => ::core::result::Result::Err(#invalid_input_error),
+            }
+        }
+    });
+
+    let try_from_impl = quote! {
+        impl<'a> ::core::convert::TryFrom<&'a [u8]> for #name {
+            type Error = #parse_error_type;
+
+            fn try_from(value: &'a [u8]) -> ::core::result::Result<Self, #parse_error_type> {
+                Self::from_bytes(value)
+            }
+        }
+    };
+
+    (methods, try_from_impl)
+}
+
+/// Generates `parse_prefix`, trying the parseable strings from longest to shortest so the longest match wins
+fn prefix_method(name: &Ident, variants: &[ParsedVariant], table: bool) -> TokenStream2 {
+    if table {
+        return quote! {
+            /// Parse the variant whose display string is the longest prefix of `input`, returning it with the rest of the input
+            pub fn parse_prefix(input: &str) -> ::core::option::Option<(Self, &str)> {
+                let mut best: ::core::option::Option<usize> = ::core::option::Option::None;
+                for (index, string) in Self::ENUM2STR_STRINGS.iter().enumerate() {
+                    if input.starts_with(string)
+                        && best.is_none_or(|best| string.len() > Self::ENUM2STR_STRINGS[best].len())
+                    {
+                        best = ::core::option::Option::Some(index);
+                    }
+                }
+                best.map(|index| {
+                    (
+                        Self::ENUM2STR_VARIANTS[index],
+                        &input[Self::ENUM2STR_STRINGS[index].len()..],
+                    )
+                })
+            }
+        };
+    }
+
+    let mut candidates = variants
+        .iter()
+        .filter_map(|parsed| parsed.parse_string().map(|string| (string, parsed)))
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|(string, _)| std::cmp::Reverse(string.len()));
+
+    let checks = candidates.iter().map(|(string, parsed)| {
+        let constructor = parsed.constructor(name);
+        quote_spanned! {
+            parsed.variant.span() =>
+                if let ::core::option::Option::Some(rest) = input.strip_prefix(#string) {
+                    return ::core::option::Option::Some((#constructor, rest));
+                }
+        }
+    });
+
+    quote! {
+        /// Parse the variant whose display string is the longest prefix of `input`, returning it with the rest of the input
+        pub fn parse_prefix(input: &str) -> ::core::option::Option<(Self, &str)> {
+            #(#checks)*
+            ::core::option::Option::None
+        }
+    }
+}
+
+/// Generates `complete`, `complete_with` and `complete_described` over the sorted strings of unit variants that
+/// are not skipped
+fn complete_methods(name: &Ident, variants: &[ParsedVariant], table: bool) -> TokenStream2 {
+    let mut completions: Vec<(String, usize, String)> = Vec::new();
+    for (index, parsed) in variants
+        .iter()
+        .enumerate()
+        .filter(|(_, parsed)| !parsed.skip)
+    {
+        if let Some(string) = parsed.parse_string() {
+            if !string.is_empty()
+                && completions
+                    .iter()
+                    .all(|(existing, _, _)| *existing != string)
+            {
+                completions.push((string, index, doc_comment(&parsed.variant.attrs)));
+            }
+        }
+    }
+    completions.sort_by(|(left, _, _), (right, _, _)| left.cmp(right));
+
+    let completions = completions.iter().map(|(_, index, doc)| {
+        let string = string_expr(name, variants, *index, table);
+        let description = if doc.is_empty() {
+            quote!(::core::option::Option::None)
+        } else {
+            quote!(::core::option::Option::Some(#doc))
+        };
+        quote!((#string, #description))
+    });
+
+    quote! {
+        /// Get the display strings of unit variants starting with `prefix`, in sorted order
+        pub fn complete(prefix: &str) -> impl ::core::iter::Iterator<Item = &'static str> + '_ {
+            Self::complete_with(prefix, false)
+        }
+
+        /// Get the display strings of unit variants starting with `prefix`, optionally ignoring ASCII case
+        pub fn complete_with(
+            prefix: &str,
+            case_insensitive: bool,
+        ) -> impl ::core::iter::Iterator<Item = &'static str> + '_ {
+            Self::complete_described(prefix, case_insensitive).map(|(completion, _)| completion)
+        }
+
+        /// Get the display strings of unit variants starting with `prefix`, along with their variants' doc comments
+        pub fn complete_described(
+            prefix: &str,
+            case_insensitive: bool,
+        ) -> impl ::core::iter::Iterator<Item = (&'static str, ::core::option::Option<&'static str>)> + '_ {
+            static COMPLETIONS: &[(&str, ::core::option::Option<&str>)] = &[#(#completions),*];
+
+            COMPLETIONS.iter().copied().filter(move |(completion, _)| {
+                completion
+                    .as_bytes()
+                    .get(..prefix.len())
+                    .is_some_and(|head| {
+                        if case_insensitive {
+                            head.eq_ignore_ascii_case(prefix.as_bytes())
+                        } else {
+                            head == prefix.as_bytes()
+                        }
+                    })
+            })
+        }
+    }
+}
+
+/// Generates `suggestions`, ranking the accepted strings by their edit distance to an input
+fn suggestions_method(variants: &[ParsedVariant], table: bool) -> TokenStream2 {
+    let candidates = if table {
+        quote! {
+            Self::ENUM2STR_EXPECTED
+                .iter()
+                .map(|index| Self::ENUM2STR_STRINGS[*index])
+        }
+    } else {
+        let candidates = expected_strings(variants);
+        quote! {{
+            static CANDIDATES: &[&str] = &[#(#candidates),*];
+            CANDIDATES.iter().copied()
+        }}
+    };
+
+    quote! {
+        /// Get the strings FromStr accepts that are within a few edits of `input` ignoring ASCII case, closest first
+        pub fn suggestions(input: &str) -> ::alloc::vec::Vec<&'static str> {
+            let input = input
+                .chars()
+                .map(|character| character.to_ascii_lowercase())
+                .collect::<::alloc::vec::Vec<_>>();
+            let mut ranked = #candidates
+                .filter_map(|candidate| {
+                    let mut previous = (0..=input.len()).collect::<::alloc::vec::Vec<_>>();
+                    let mut length = 0;
+                    for (row, character) in candidate.chars().enumerate() {
+                        let character = character.to_ascii_lowercase();
+                        let mut current = ::alloc::vec![row + 1];
+                        for (column, other) in input.iter().enumerate() {
+                            let substitution = previous[column] + usize::from(*other != character);
+                            current.push(substitution.min(previous[column + 1] + 1).min(current[column] + 1));
+                        }
+                        previous = current;
+                        length = row + 1;
+                    }
+                    let distance = previous[input.len()];
+                    (distance <= (length / 3).max(1)).then_some((distance, candidate))
+                })
+                .collect::<::alloc::vec::Vec<_>>();
+            ranked.sort_by_key(|(distance, _)| *distance);
+            ranked.into_iter().map(|(_, candidate)| candidate).collect()
+        }
+    }
+}
+
+/// Generates the `{Name}Set` bitset of an all-unit enum, holding one bit per variant in declaration order
+///
+/// Display strings must be non-empty, trimmed and free of the separator so that every set round-trips through FromStr.
+fn set_type(
+    name: &Ident,
+    vis: &syn::Visibility,
+    variants: &[ParsedVariant],
+    separator: Option<&LitStr>,
+) -> Result<TokenStream2, Error> {
+    let set_name = Ident::new(&format!("{}Set", name), name.span());
+    let separator_value = separator.map_or_else(|| ",".to_string(), LitStr::value);
+    if separator_value.is_empty() {
+        return Err(Error::new_spanned(
+            separator,
+            "The set separator must not be empty",
+        ));
+    }
+    for parsed in variants {
+        let string = parsed.template();
+        if string.is_empty() || string.trim() != string {
+            return Err(Error::new_spanned(
+                parsed.variant,
+                "#[enum2str(set)] requires non-empty display strings without surrounding whitespace",
+            ));
+        }
+        if string.contains(&separator_value) {
+            let message = format!(
+                "The display string {:?} contains the set separator {:?}",
+                string, separator_value
+            );
+            return Err(Error::new_spanned(parsed.variant, message));
+        }
+    }
+    let separator = separator_value;
+    let count = variants.len();
+    let words = count.div_ceil(64);
+    let full = (0..words).map(|word| {
+        let bits = (count - word * 64).min(64);
+        if bits == 64 {
+            u64::MAX
+        } else {
+            (1u64 << bits) - 1
+        }
+    });
+    let parse_error_type = parse_error_type();
+    let doc = format!(
+        " A set of `{}` variants, stored as one bit per variant",
+        name
+    );
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        #vis struct #set_name {
+            bits: [u64; #words],
+        }
+
+        #[allow(deprecated)]
+        impl #set_name {
+            /// The separator the set is displayed and parsed with
+            pub const SEPARATOR: &'static str = #separator;
+
+            /// Create an empty set
+            pub const fn new() -> Self {
+                Self { bits: [0; #words] }
+            }
+
+            /// Create a set of every variant
+            pub const fn all() -> Self {
+                Self { bits: [#(#full),*] }
+            }
+
+            /// Add a variant, returning whether it was newly added
+            pub fn insert(&mut self, variant: #name) -> bool {
+                let index = variant.variant_index();
+                let added = !self.contains(&variant);
+                self.bits[index / 64] |= 1 << (index % 64);
+                added
+            }
+
+            /// Remove a variant, returning whether it was present
+            pub fn remove(&mut self, variant: &#name) -> bool {
+                let index = variant.variant_index();
+                let present = self.contains(variant);
+                self.bits[index / 64] &= !(1 << (index % 64));
+                present
+            }
+
+            /// Check whether the set holds a variant
+            pub fn contains(&self, variant: &#name) -> bool {
+                let index = variant.variant_index();
+                self.bits[index / 64] & (1 << (index % 64)) != 0
+            }
+
+            /// Get the number of variants in the set
+            pub fn len(&self) -> usize {
+                self.bits.iter().map(|word| word.count_ones() as usize).sum()
+            }
+
+            /// Check whether the set holds no variants
+            pub fn is_empty(&self) -> bool {
+                self.bits.iter().all(|word| *word == 0)
+            }
+
+            /// Get the variants in either set
+            pub fn union(&self, other: &Self) -> Self {
+                let mut bits = self.bits;
+                for (word, other) in bits.iter_mut().zip(other.bits) {
+                    *word |= other;
+                }
+                Self { bits }
+            }
+
+            /// Get the variants in both sets
+            pub fn intersection(&self, other: &Self) -> Self {
+                let mut bits = self.bits;
+                for (word, other) in bits.iter_mut().zip(other.bits) {
+                    *word &= other;
+                }
+                Self { bits }
+            }
+
+            /// Get the variants in this set but not in `other`
+            pub fn difference(&self, other: &Self) -> Self {
+                let mut bits = self.bits;
+                for (word, other) in bits.iter_mut().zip(other.bits) {
+                    *word &= !other;
+                }
+                Self { bits }
+            }
+
+            /// Get the variants in exactly one of the sets
+            pub fn symmetric_difference(&self, other: &Self) -> Self {
+                let mut bits = self.bits;
+                for (word, other) in bits.iter_mut().zip(other.bits) {
+                    *word ^= other;
+                }
+                Self { bits }
+            }
+
+            /// Check whether every variant in this set is also in `other`
+            pub fn is_subset(&self, other: &Self) -> bool {
+                self.difference(other).is_empty()
+            }
+
+            /// Iterate over the variants in the set in declaration order
+            pub fn iter(&self) -> impl ::core::iter::Iterator<Item = #name> + '_ {
+                (0..#count)
+                    .filter(move |index| self.bits[index / 64] & (1 << (index % 64)) != 0)
+                    .map(#name::enum2str_variant)
+            }
+        }
+
+        impl ::core::ops::BitOr for #set_name {
+            type Output = Self;
+
+            fn bitor(self, other: Self) -> Self {
+                self.union(&other)
+            }
+        }
+
+        impl ::core::ops::BitAnd for #set_name {
+            type Output = Self;
+
+            fn bitand(self, other: Self) -> Self {
+                self.intersection(&other)
+            }
+        }
+
+        impl ::core::ops::BitXor for #set_name {
+            type Output = Self;
+
+            fn bitxor(self, other: Self) -> Self {
+                self.symmetric_difference(&other)
+            }
+        }
+
+        impl ::core::ops::Sub for #set_name {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                self.difference(&other)
+            }
+        }
+
+        impl ::core::convert::From<#name> for #set_name {
+            fn from(variant: #name) -> Self {
+                let mut set = Self::new();
+                set.insert(variant);
+                set
+            }
+        }
+
+        impl ::core::iter::Extend<#name> for #set_name {
+            fn extend<I: ::core::iter::IntoIterator<Item = #name>>(&mut self, variants: I) {
+                for variant in variants {
+                    self.insert(variant);
+                }
+            }
+        }
+
+        impl ::core::iter::FromIterator<#name> for #set_name {
+            fn from_iter<I: ::core::iter::IntoIterator<Item = #name>>(variants: I) -> Self {
+                let mut set = Self::new();
+                set.extend(variants);
+                set
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::fmt::Debug for #set_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                f.debug_set()
+                    .entries(self.iter().map(|variant| variant.as_str()))
+                    .finish()
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::fmt::Display for #set_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                for (position, variant) in self.iter().enumerate() {
+                    if position > 0 {
+                        f.write_str(Self::SEPARATOR)?;
+                    }
+                    f.write_str(variant.as_str())?;
+                }
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        impl ::core::str::FromStr for #set_name {
+            type Err = #parse_error_type;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                let mut set = Self::new();
+                if !s.trim().is_empty() {
+                    for item in s.split(Self::SEPARATOR) {
+                        set.insert(item.trim().parse()?);
+                    }
+                }
+                ::core::result::Result::Ok(set)
+            }
+        }
+    })
+}
+
+/// Generates the `VARIANT_INFO` table along with the `{Name}VariantInfo` and `{Name}VariantKind` types it is built from
+fn info_items(
+    name: &Ident,
+    vis: &syn::Visibility,
+    variants: &[ParsedVariant],
+    table: bool,
+) -> (TokenStream2, TokenStream2) {
+    let info_name = Ident::new(&format!("{}VariantInfo", name), name.span());
+    let kind_name = Ident::new(&format!("{}VariantKind", name), name.span());
+
+    let infos = variants.iter().enumerate().map(|(index, parsed)| {
+        let variant = parsed.variant;
+        let variant_name = variant.ident.to_string();
+        let template = string_expr(name, variants, index, table);
+        let doc = doc_comment(&variant.attrs);
+        let (kind, fields) = match &variant.fields {
+            Fields::Unit => (quote!(Unit), Vec::new()),
+            Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => (
+                quote!(Tuple),
+                (0..unnamed.len()).map(|index| index.to_string()).collect(),
+            ),
+            Fields::Named(FieldsNamed { named, .. }) => (
+                quote!(Named),
+                named
+                    .iter()
+                    .filter_map(|field| field.ident.as_ref().map(ToString::to_string))
+                    .collect(),
+            ),
+        };
+        let attributes = variant
+            .attrs
+            .iter()
+            .filter(|attr| !attr.path.is_ident("doc"))
+            .map(|attr| attr.path.to_token_stream().to_string().replace(' ', ""));
+        let groups = &parsed.groups;
+
+        quote! {
+            #info_name {
+                name: #variant_name,
+                template: #template,
+                kind: #kind_name::#kind,
+                fields: &[#(#fields),*],
+                attributes: &[#(#attributes),*],
+                groups: &[#(#groups),*],
+                doc: #doc,
+            }
+        }
+    });
+
+    let info_doc = format!(" A description of a `{}` variant", name);
+    let kind_doc = format!(" The shape of a `{}` variant's fields", name);
+
+    let info_const = quote! {
+        /// Descriptions of this enum's variants, in declaration order
+        pub const VARIANT_INFO: &'static [#info_name] = &[#(#infos),*];
+    };
+
+    let info_types = quote! {
+        #[doc = #kind_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis enum #kind_name {
+            /// A variant without fields
+            Unit,
+            /// A variant with unnamed fields
+            Tuple,
+            /// A variant with named fields
+            Named,
+        }
+
+        #[doc = #info_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis struct #info_name {
+            /// The variant's name as declared
+            pub name: &'static str,
+            /// The format specifier used to display the variant
+            pub template: &'static str,
+            /// The shape of the variant's fields
+            pub kind: #kind_name,
+            /// The names of the variant's fields, or their positions for unnamed fields
+            pub fields: &'static [&'static str],
+            /// The paths of the variant's attributes other than doc comments
+            pub attributes: &'static [&'static str],
+            /// The groups the variant belongs to
+            pub groups: &'static [&'static str],
+            /// The variant's doc comment, or an empty string
+            pub doc: &'static str,
+        }
+    };
+
+    (info_const, info_types)
+}
+
+fn has_explicit_discriminants(data: &syn::DataEnum) -> bool {
+    data.variants
+        .iter()
+        .any(|variant| variant.discriminant.is_some())
+}
+
+/// Generates the private `enum2str_variant` lookup from a position in declaration order to the variant of a
+/// non-empty all-unit enum, which the ordinal, iteration, numeric and set helpers share
+fn index_lookup(name: &Ident, variants: &[ParsedVariant], table: bool) -> TokenStream2 {
+    if table {
+        return quote! {
+            fn enum2str_variant(index: usize) -> Self {
+                Self::ENUM2STR_VARIANTS[index]
+            }
+        };
+    }
+
+    let Some((last, rest)) = variants.split_last() else {
+        return TokenStream2::new();
+    };
+    let arms = rest.iter().enumerate().map(|(index, parsed)| {
+        let variant_name = &parsed.variant.ident;
+        quote_spanned!(parsed.variant.span() => #index => #name::#variant_name,)
+    });
+    let last_name = &last.variant.ident;
+
+    quote! {
+        fn enum2str_variant(index: usize) -> Self {
+            match index {
+                #(#arms)*
+                _ => #name::#last_name,
+            }
+        }
+    }
+}
+
+/// An expression converting the `usize` named `index` to the variant at that position, if any, through the
+/// `index_lookup` helper
+fn checked_index_lookup(variants: &[ParsedVariant]) -> TokenStream2 {
+    let count = variants.len();
+    quote!((index < #count).then(|| Self::enum2str_variant(index)))
+}
+
+/// Generates the private `enum2str_from_discriminant` lookup for enums that declare explicit discriminants of
+/// `discriminant_type`
+fn discriminant_lookup(
+    name: &Ident,
+    variants: &[ParsedVariant],
+    discriminant_type: &TokenStream2,
+) -> TokenStream2 {
+    let checks = variants.iter().map(|parsed| {
+        let variant_name = &parsed.variant.ident;
+        quote_spanned! {
+            parsed.variant.span() =>
+                if discriminant == #name::#variant_name as #discriminant_type {
+                    return ::core::option::Option::Some(#name::#variant_name);
+                }
+        }
+    });
+
+    quote! {
+        fn enum2str_from_discriminant(
+            discriminant: #discriminant_type,
+        ) -> ::core::option::Option<Self> {
+            #(#checks)*
+            ::core::option::Option::None
+        }
+    }
+}
+
+/// Generates `from_index` and `index` for all-unit enums, along with `from_discriminant` and `discriminant` when
+/// the enum declares explicit discriminants of `discriminant_type`
+fn ordinal_methods(
+    name: &Ident,
+    variants: &[ParsedVariant],
+    discriminant_type: Option<&TokenStream2>,
+) -> TokenStream2 {
+    let lookup = checked_index_lookup(variants);
+
+    let mut methods = quote! {
+        /// Get the variant at a position in declaration order
+        pub fn from_index(index: usize) -> ::core::option::Option<Self> {
+            #lookup
+        }
+
+        /// Get the position of a variant in declaration order
+        pub fn index(&self) -> usize {
+            self.variant_index()
+        }
+    };
+
+    if let Some(discriminant_type) = discriminant_type {
+        let discriminant_arms = variants.iter().map(|parsed| {
+            let variant_name = &parsed.variant.ident;
+            quote_spanned!(parsed.variant.span() => #name::#variant_name => #name::#variant_name as #discriminant_type,)
+        });
+
+        methods.extend(quote! {
+            /// Get the variant with a discriminant
+            pub fn from_discriminant(discriminant: #discriminant_type) -> ::core::option::Option<Self> {
+                Self::enum2str_from_discriminant(discriminant)
+            }
+
+            /// Get the discriminant of a variant
+            pub fn discriminant(&self) -> #discriminant_type {
+                match self {
+                    #(#discriminant_arms)*
+                }
+            }
+        });
+    }
+
+    methods
+}
+
+/// Generates `iter`, `next`, `prev` and their wrapping forms, following declaration order over the variants that
+/// are not skipped
+fn iteration_methods(variants: &[ParsedVariant]) -> TokenStream2 {
+    let indices = variants
+        .iter()
+        .enumerate()
+        .filter(|(_, parsed)| !parsed.skip)
+        .map(|(index, _)| index);
+
+    quote! {
+        const ENUM2STR_ITERATION_INDICES: &'static [usize] = &[#(#indices),*];
+
+        /// Iterate over the variants that are not skipped, in declaration order
+        pub fn iter() -> impl ::core::iter::DoubleEndedIterator<Item = Self> + ::core::iter::ExactSizeIterator {
+            Self::ENUM2STR_ITERATION_INDICES
+                .iter()
+                .map(|index| Self::enum2str_variant(*index))
+        }
+
+        /// Get the next variant that is not skipped, in declaration order
+        pub fn next(&self) -> ::core::option::Option<Self> {
+            let current = self.variant_index();
+            Self::ENUM2STR_ITERATION_INDICES
+                .iter()
+                .find(|index| **index > current)
+                .map(|index| Self::enum2str_variant(*index))
+        }
+
+        /// Get the previous variant that is not skipped, in declaration order
+        pub fn prev(&self) -> ::core::option::Option<Self> {
+            let current = self.variant_index();
+            Self::ENUM2STR_ITERATION_INDICES
+                .iter()
+                .rev()
+                .find(|index| **index < current)
+                .map(|index| Self::enum2str_variant(*index))
+        }
+
+        /// Get the next variant that is not skipped, wrapping around to the first
+        pub fn next_wrapping(&self) -> ::core::option::Option<Self> {
+            self.next().or_else(|| Self::iter().next())
+        }
+
+        /// Get the previous variant that is not skipped, wrapping around to the last
+        pub fn prev_wrapping(&self) -> ::core::option::Option<Self> {
+            self.prev().or_else(|| Self::iter().next_back())
+        }
+    }
+}
+
+/// Generates the fieldless `kind` twin of an enum along with its own derived impls and iteration helpers, and
+/// `kind()` mapping each variant to its twin. Unit variants keep their strings, variants keep their groups, and data variants display as
+/// their names.
+fn kind_items(
+    name: &Ident,
+    vis: &syn::Visibility,
+    variants: &[ParsedVariant],
+    kind: &Ident,
+) -> TokenStream2 {
+    let mut definition_variants = Vec::new();
+    let mut derive_variants = Vec::new();
+    let mut kind_arms = TokenStream2::new();
+
+    for parsed in variants {
+        let variant_name = &parsed.variant.ident;
+        let attrs = parsed
+            .variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("doc") || attr.path.is_ident("deprecated"))
+            .collect::<Vec<_>>();
+
+        let mut options = Vec::new();
+        if let (Some(literal), Fields::Unit) = (&parsed.literal, &parsed.variant.fields) {
+            options.push(literal.to_token_stream());
+        }
+        if parsed.skip {
+            options.push(quote!(skip));
+        }
+        for group in &parsed.groups {
+            options.push(quote!(group = #group));
+        }
+        let options = (!options.is_empty()).then(|| quote!(#[enum2str(#(#options),*)]));
+
+        definition_variants.push(quote!(#(#attrs)* #variant_name));
+        derive_variants.push(quote!(#(#attrs)* #options #variant_name));
+
+        let pattern = parsed.wildcard_pattern(name);
+        kind_arms.extend(quote_spanned! {
+            parsed.variant.span() =>
+                #pattern => #kind::#variant_name,
+        });
+    }
+
+    let doc = format!(" The kind of a `{}` variant, without its fields", name);
+    let derive_input: DeriveInput = syn::parse_quote! {
+        #[enum2str(iter)]
+        #vis enum #kind {
+            #(#derive_variants),*
+        }
+    };
+    let kind_impls = expand(&derive_input);
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis enum #kind {
+            #(#definition_variants),*
+        }
+
+        #kind_impls
+
+        #[allow(deprecated)]
+        impl #name {
+            /// Get the fieldless kind of a variant
+            pub fn kind(&self) -> #kind {
+                match self {
+                    #kind_arms
+                }
+            }
+        }
+    }
+}
+
+/// The `{Name}PropValue` variant a prop literal is stored as, along with its value and payload type
+fn prop_value(lit: &syn::Lit) -> Result<(TokenStream2, TokenStream2, TokenStream2), Error> {
+    Ok(match lit {
+        syn::Lit::Str(value) => (quote!(Str), quote!(#value), quote!(&'static str)),
+        syn::Lit::Int(value) => {
+            let value = value.base10_parse::<i64>()?;
+            (quote!(Int), quote!(#value), quote!(i64))
+        }
+        syn::Lit::Float(value) => {
+            let value = value.base10_parse::<f64>()?;
+            (quote!(Float), quote!(#value), quote!(f64))
+        }
+        syn::Lit::Bool(value) => (quote!(Bool), quote!(#value), quote!(bool)),
+        _ => {
+            return Err(Error::new_spanned(
+                lit,
+                "Props must be strings, integers, floats or booleans",
+            ));
+        }
+    })
+}
+
+/// Generates `get_prop` and a `prop_<key>` accessor per prop key, along with the `{Name}PropValue` type they return.
+/// Every variant must declare the `required` props, and each key must hold the same type on every variant.
+fn prop_items(
+    name: &Ident,
+    vis: &syn::Visibility,
+    variants: &[ParsedVariant],
+    required: &[Ident],
+) -> Result<(TokenStream2, TokenStream2), Error> {
+    let value_name = Ident::new(&format!("{}PropValue", name), name.span());
+
+    let mut keys: Vec<Ident> = required.to_vec();
+    for parsed in variants {
+        for key in required {
+            if parsed.props.iter().all(|(existing, _)| existing != key) {
+                return Err(Error::new_spanned(
+                    &parsed.variant.ident,
+                    format!("Variant is missing the required prop `{}`", key),
+                ));
+            }
+        }
+        for (key, _) in &parsed.props {
+            if !keys.contains(key) {
+                keys.push(key.clone());
+            }
+        }
+    }
+
+    let mut accessors = TokenStream2::new();
+    let mut get_arms = TokenStream2::new();
+    for key in &keys {
+        let mut kind: Option<(String, TokenStream2, TokenStream2)> = None;
+        let mut arms = TokenStream2::new();
+        let mut declared = 0;
+        for parsed in variants {
+            let Some((_, lit)) = parsed.props.iter().find(|(existing, _)| existing == key) else {
+                continue;
+            };
+            let (variant_kind, value, payload) = prop_value(lit)?;
+            match &kind {
+                Some((existing, _, _)) if *existing != variant_kind.to_string() => {
+                    return Err(Error::new_spanned(
+                        lit,
+                        format!("Prop `{}` has a different type on another variant", key),
+                    ));
+                }
+                Some(_) => {}
+                None => kind = Some((variant_kind.to_string(), variant_kind, payload)),
+            }
+
+            let pattern = parsed.wildcard_pattern(name);
+            let value = if required.contains(key) {
+                value
+            } else {
+                quote!(::core::option::Option::Some(#value))
+            };
+            arms.extend(quote_spanned!(parsed.variant.span() => #pattern => #value,));
+            declared += 1;
+        }
+
+        let Some((_, variant_kind, payload)) = kind else {
+            continue;
+        };
+        let accessor = Ident::new(&format!("prop_{}", key), key.span());
+        let key_string = key.to_string();
+        let doc = format!(" Get the `{}` prop of a variant", key);
+
+        if required.contains(key) {
+            accessors.extend(quote! {
+                #[doc = #doc]
+                pub const fn #accessor(&self) -> #payload {
+                    match self {
+                        #arms
+                    }
+                }
+            });
+            get_arms.extend(quote! {
+                #key_string => ::core::option::Option::Some(#value_name::#variant_kind(self.#accessor())),
+            });
+        } else {
+            if declared < variants.len() {
+                arms.extend(quote!(_ => ::core::option::Option::None,));
+            }
+            accessors.extend(quote! {
+                #[doc = #doc]
+                pub const fn #accessor(&self) -> ::core::option::Option<#payload> {
+                    match self {
+                        #arms
+                    }
+                }
+            });
+            get_arms.extend(quote! {
+                #key_string => self.#accessor().map(#value_name::#variant_kind),
+            });
+        }
+    }
+
+    let methods = quote! {
+        #accessors
+
+        /// Get a prop of a variant by its key
+        pub fn get_prop(&self, key: &str) -> ::core::option::Option<#value_name> {
+            match key {
+                #get_arms
+                _ => ::core::option::Option::None,
+            }
+        }
+    };
+
+    let doc = format!(" The value of a `{}` prop", name);
+    let types = quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #vis enum #value_name {
+            /// A string prop
+            Str(&'static str),
+            /// An integer prop
+            Int(i64),
+            /// A floating point prop
+            Float(f64),
+            /// A boolean prop
+            Bool(bool),
+        }
+    };
+
+    Ok((methods, types))
+}
+
+/// Generates `groups` and `in_group`, along with `variants_in_group` for all-unit enums
+fn group_methods(name: &Ident, data: &syn::DataEnum, variants: &[ParsedVariant]) -> TokenStream2 {
+    let group_arms = variants.iter().map(|parsed| {
+        let pattern = parsed.wildcard_pattern(name);
+        let groups = &parsed.groups;
+        quote_spanned!(parsed.variant.span() => #pattern => &[#(#groups),*],)
+    });
+
+    let mut methods = quote! {
+        /// Get the groups a variant belongs to, in declaration order
+        pub fn groups(&self) -> &'static [&'static str] {
+            match self {
+                #(#group_arms)*
+            }
+        }
+
+        /// Check whether a variant belongs to a group
+        pub fn in_group(&self, group: &str) -> bool {
+            self.groups().contains(&group)
+        }
+    };
+
+    if has_only_unit_variants(data) {
+        let mut group_names: Vec<String> = Vec::new();
+        for group in variants.iter().flat_map(|parsed| &parsed.groups) {
+            if !group_names.contains(&group.value()) {
+                group_names.push(group.value());
+            }
+        }
+
+        let arms = group_names.iter().map(|group| {
+            let members = variants
+                .iter()
+                .filter(|parsed| {
+                    parsed
+                        .groups
+                        .iter()
+                        .any(|existing| existing.value() == *group)
+                })
+                .map(|parsed| {
+                    let variant_name = &parsed.variant.ident;
+                    quote!(#name::#variant_name)
+                });
+            quote! {
+                #group => {
+                    const MEMBERS: &[#name] = &[#(#members),*];
+                    MEMBERS
+                }
+            }
+        });
+
+        methods.extend(quote! {
+            /// Get the variants in a group, in declaration order
+            pub fn variants_in_group(group: &str) -> &'static [Self] {
+                match group {
+                    #(#arms)*
+                    _ => &[],
+                }
+            }
+        });
+    }
+
+    methods
+}
+
+/// Camel-cases a representation name into its `{Name}Repr` variant name, e.g. `wire_format` into `WireFormat`
+fn repr_variant_name(repr: &Ident) -> String {
+    words(&repr.to_string())
+        .iter()
+        .map(|word| {
+            let mut characters = word.chars();
+            characters.next().map_or_else(String::new, |first| {
+                first
+                    .to_uppercase()
+                    .chain(characters.flat_map(char::to_lowercase))
+                    .collect()
+            })
+        })
+        .collect()
+}
+
+/// Checks each variant's representations against the names listed in `reprs(...)`, and makes the default
+/// representation the display string of the variants that declare it
+fn apply_reprs(variants: &mut [ParsedVariant], options: &EnumOptions) -> Result<(), Error> {
+    for parsed in variants.iter_mut() {
+        for (key, _) in &parsed.reprs {
+            if !options.reprs.contains(key) {
+                return Err(Error::new_spanned(
+                    key,
+                    "Unknown representation, expected one listed in #[enum2str(reprs(...))]",
+                ));
+            }
+        }
+
+        let default = options.default_repr.as_ref().and_then(|default_repr| {
+            parsed
+                .reprs
+                .iter()
+                .find(|(key, _)| key == default_repr)
+                .map(|(_, value)| value.clone())
+        });
+        if let Some(default) = default {
+            if parsed.literal.is_some() {
+                return Err(Error::new_spanned(
+                    &parsed.variant.ident,
+                    "A variant with a default representation cannot also declare a display string",
+                ));
+            }
+            parsed.literal = Some(default);
+        }
+    }
+    Ok(())
+}
+
+/// Generates the `{Name}Repr` type along with `to_str_as`, `from_str_as` and `repr_strings`. Variants without a
+/// string for a representation fall back to their display string.
+fn repr_items(
+    name: &Ident,
+    vis: &syn::Visibility,
+    variants: &[ParsedVariant],
+    reprs: &[Ident],
+) -> (TokenStream2, TokenStream2) {
+    let repr_name = Ident::new(&format!("{}Repr", name), name.span());
+    let repr_variants = reprs
+        .iter()
+        .map(|repr| Ident::new(&repr_variant_name(repr), repr.span()))
+        .collect::<Vec<_>>();
+
+    let strings = reprs
+        .iter()
+        .map(|repr| {
+            variants
+                .iter()
+                .map(|parsed| {
+                    parsed
+                        .reprs
+                        .iter()
+                        .find(|(key, _)| key == repr)
+                        .map_or_else(|| parsed.template(), |(_, value)| value.value())
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let input = Ident::new("value", Span::call_site());
+    let dispatch_arms = repr_variants
+        .iter()
+        .zip(&strings)
+        .map(|(repr_variant, strings)| {
+            let candidates = variants
+                .iter()
+                .zip(strings)
+                .map(|(parsed, string)| {
+                    let variant_name = &parsed.variant.ident;
+                    Candidate {
+                        bytes: string.clone().into_bytes(),
+                        variant: quote_spanned!(parsed.variant.span() => #name::#variant_name),
+                    }
+                })
+                .collect::<Vec<_>>();
+            let dispatch = candidate_dispatch(&candidates, &input);
+            quote!(#repr_name::#repr_variant => #dispatch,)
+        });
+
+    let tables = strings.iter().map(|strings| quote!(&[#(#strings),*]));
+    let parse_error_type = parse_error_type();
+    let invalid_input_error = if cfg!(feature = "alloc") {
+        quote!(::alloc::format!(
+            "Invalid {} {:?} representation: {}",
+            stringify!(#name),
+            repr,
+            s
+        ))
+    } else {
+        let message = format!("Invalid {} variant", name);
+        quote!(#message)
+    };
+    let repr_docs = reprs
+        .iter()
+        .map(|repr| format!(" The `{}` representation", repr));
+    let doc = format!(" A named string representation of `{}` variants", name);
+
+    let methods = quote! {
+        const ENUM2STR_REPR_STRINGS: &'static [&'static [&'static str]] = &[#(#tables),*];
+
+        /// Get the strings of a representation, in declaration order
+        pub const fn repr_strings(repr: #repr_name) -> &'static [&'static str] {
+            Self::ENUM2STR_REPR_STRINGS[repr as usize]
+        }
+
+        /// Get the string of a variant in a representation
+        pub fn to_str_as(&self, repr: #repr_name) -> &'static str {
+            Self::repr_strings(repr)[self.variant_index()]
+        }
+
+        /// Parse a variant from its string in a representation
+        pub fn from_str_as(repr: #repr_name, s: &str) -> ::core::result::Result<Self, #parse_error_type> {
+            let #input = s.as_bytes();
+            let variant = match repr {
+                #(#dispatch_arms)*
+            };
+            variant.ok_or_else(|| #invalid_input_error)
+        }
+    };
+
+    let types = quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis enum #repr_name {
+            #(
+                #[doc = #repr_docs]
+                #repr_variants
+            ),*
+        }
+    };
+
+    (methods, types)
+}
+
+/// Generates `display_in`, writing a variant's template for a locale with the same bindings Display uses.
+/// Each locale's template must refer to the same placeholders as the variant's own template.
+fn locale_method(name: &Ident, variants: &[ParsedVariant]) -> Result<TokenStream2, Error> {
+    let mut arms = TokenStream2::new();
+
+    for parsed in variants.iter().filter(|parsed| !parsed.locales.is_empty()) {
+        let variant_name = &parsed.variant.ident;
+        let (pattern, arguments) = if parsed.is_format_string() {
+            let expected = placeholder_arguments(&parsed.template());
+            for (locale, template) in &parsed.locales {
+                let found = placeholder_arguments(&template.value());
+                if found != expected {
+                    return Err(Error::new_spanned(
+                        template,
+                        format!(
+                            "Locale `{}` uses the placeholders {:?}, but the template uses {:?}",
+                            locale, found, expected
+                        ),
+                    ));
+                }
+            }
+
+            match &parsed.variant.fields {
+                Fields::Named(_) => {
+                    let fields = expected
+                        .iter()
+                        .map(|field| syn::parse_str::<Ident>(field))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    (
+                        quote!(#name::#variant_name { #(#fields,)* .. }),
+                        quote!(#(#fields = #fields),*),
+                    )
+                }
+                _ => {
+                    let bindings = parsed
+                        .argument_fields()
+                        .into_iter()
+                        .map(|(binding, _)| binding);
+                    (parsed.argument_pattern(name), quote!(#(#bindings),*))
+                }
+            }
+        } else {
+            (parsed.wildcard_pattern(name), TokenStream2::new())
+        };
+
+        let locale_arms = parsed.locales.iter().map(|(locale, template)| {
+            let locale = locale.to_string();
+            if parsed.is_format_string() {
+                quote!(#locale => ::core::write!(f, #template, #arguments),)
+            } else {
+                quote!(#locale => f.write_str(#template),)
+            }
+        });
+
+        arms.extend(quote_spanned! {
+            parsed.variant.span() =>
+                #pattern => match self.locale {
+                    #(#locale_arms)*
+                    _ => ::core::fmt::Display::fmt(self.variant, f),
+                },
+        });
+    }
+
+    if variants.iter().any(|parsed| parsed.locales.is_empty()) {
+        arms.extend(quote!(variant => ::core::fmt::Display::fmt(variant, f),));
+    }
+
+    Ok(quote! {
+        /// Display a variant with its template for `locale`, or its default template when it has none for the locale
+        pub fn display_in<'a>(&'a self, locale: &'a str) -> impl ::core::fmt::Display + 'a {
+            struct Localized<'a> {
+                variant: &'a #name,
+                locale: &'a str,
+            }
+
+            #[allow(deprecated)]
+            impl ::core::fmt::Display for Localized<'_> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    match self.variant {
+                        #arms
+                    }
+                }
+            }
+
+            Localized {
+                variant: self,
+                locale,
+            }
+        }
+    })
+}
+
+/// An Aho-Corasick automaton over ASCII-lowercased patterns, built at macro expansion time
+struct Automaton {
+    /// Each state's transitions, sorted by byte
+    edges: Vec<Vec<(u8, usize)>>,
+    /// The state to fall back to when a state has no transition for a byte
+    fail: Vec<usize>,
+    /// The patterns ending at each state, in pattern order
+    outputs: Vec<Vec<usize>>,
+}
+
+impl Automaton {
+    fn new(patterns: &[String]) -> Self {
+        let mut automaton = Self {
+            edges: vec![Vec::new()],
+            fail: vec![0],
+            outputs: vec![Vec::new()],
+        };
+
+        for (index, pattern) in patterns.iter().enumerate() {
+            let mut state = 0;
+            for byte in pattern.bytes().map(|byte| byte.to_ascii_lowercase()) {
+                state = match automaton.transition(state, byte) {
+                    Some(next) => next,
+                    None => {
+                        let next = automaton.edges.len();
+                        automaton.edges.push(Vec::new());
+                        automaton.fail.push(0);
+                        automaton.outputs.push(Vec::new());
+                        automaton.edges[state].push((byte, next));
+                        next
+                    }
+                };
+            }
+            automaton.outputs[state].push(index);
+        }
+
+        let mut queue = std::collections::VecDeque::from(
+            automaton.edges[0]
+                .iter()
+                .map(|(_, next)| *next)
+                .collect::<Vec<_>>(),
+        );
+        while let Some(state) = queue.pop_front() {
+            for (byte, next) in automaton.edges[state].clone() {
+                let mut fallback = automaton.fail[state];
+                let fail = loop {
+                    if let Some(target) = automaton.transition(fallback, byte) {
+                        break target;
+                    }
+                    if fallback == 0 {
+                        break 0;
+                    }
+                    fallback = automaton.fail[fallback];
+                };
+                automaton.fail[next] = fail;
+                let inherited = automaton.outputs[fail].clone();
+                automaton.outputs[next].extend(inherited);
+                queue.push_back(next);
+            }
+        }
+
+        for edges in automaton.edges.iter_mut() {
+            edges.sort();
+        }
+
+        automaton
+    }
+
+    fn transition(&self, state: usize, byte: u8) -> Option<usize> {
+        self.edges[state]
+            .iter()
+            .find(|(edge, _)| *edge == byte)
+            .map(|(_, next)| *next)
+    }
+}
+
+/// Generates `find_all` and `find_all_with`, scanning text for the non-empty strings of unit variants
+fn find_methods(name: &Ident, variants: &[ParsedVariant], table: bool) -> TokenStream2 {
+    let (patterns, indices): (Vec<_>, Vec<_>) = variants
+        .iter()
+        .enumerate()
+        .filter_map(|(index, parsed)| parsed.parse_string().map(|string| (string, index)))
+        .filter(|(string, _)| !string.is_empty())
+        .unzip();
+
+    let body = match indices.split_last() {
+        None => quote! {
+            let _ = (haystack, word_boundary, case_insensitive);
+            ::core::iter::empty()
+        },
+        Some((last, rest)) => {
+            let automaton = Automaton::new(&patterns);
+            let mut states = Vec::new();
+            let mut edges = Vec::new();
+            let mut outputs = Vec::new();
+            for state in 0..automaton.edges.len() {
+                let (edge_start, output_start) = (edges.len() as u32, outputs.len() as u32);
+                edges.extend(automaton.edges[state].iter().map(|(byte, next)| {
+                    let next = *next as u32;
+                    quote!((#byte, #next))
+                }));
+                outputs.extend(automaton.outputs[state].iter().map(|index| *index as u32));
+                let (edge_end, output_end) = (edges.len() as u32, outputs.len() as u32);
+                let fail = automaton.fail[state] as u32;
+                states.push(quote!((#edge_start, #edge_end, #fail, #output_start, #output_end)));
+            }
+            let max_length = patterns.iter().map(String::len).max().unwrap_or_default();
+
+            let pattern_strings = indices
+                .iter()
+                .map(|index| string_expr(name, variants, *index, table));
+            let (pattern_variants, variant_lookup) = if table {
+                (
+                    quote!(static PATTERN_VARIANTS: &[usize] = &[#(#indices),*];),
+                    quote!(#name::ENUM2STR_VARIANTS[PATTERN_VARIANTS[pattern]]),
+                )
+            } else {
+                let arms = rest.iter().enumerate().map(|(pattern, index)| {
+                    let parsed = &variants[*index];
+                    let constructor = parsed.constructor(name);
+                    quote_spanned!(parsed.variant.span() => #pattern => #constructor,)
+                });
+                let last_constructor = variants[*last].constructor(name);
+                (
+                    TokenStream2::new(),
+                    quote! {
+                        match pattern {
+                            #(#arms)*
+                            _ => #last_constructor,
+                        }
+                    },
+                )
+            };
+
+            quote! {
+                /// Each state's edge range, failure state and output range
+                static STATES: &[(u32, u32, u32, u32, u32)] = &[#(#states),*];
+                /// Transitions on ASCII-lowercased bytes, sorted by byte within each state
+                static EDGES: &[(u8, u32)] = &[#(#edges),*];
+                static OUTPUTS: &[u32] = &[#(#outputs),*];
+                static PATTERNS: &[&str] = &[#(#pattern_strings),*];
+                #pattern_variants
+                const MAX_LENGTH: usize = #max_length;
+
+                struct Matches<'a> {
+                    haystack: &'a str,
+                    position: usize,
+                    word_boundary: bool,
+                    case_insensitive: bool,
+                }
+
+                impl Matches<'_> {
+                    fn is_boundary(&self, start: usize, end: usize) -> bool {
+                        let is_word = |character: char| character.is_alphanumeric() || character == '_';
+                        !self.haystack[..start].chars().next_back().is_some_and(is_word)
+                            && !self.haystack[end..].chars().next().is_some_and(is_word)
+                    }
+                }
+
+                #[allow(deprecated)]
+                impl ::core::iter::Iterator for Matches<'_> {
+                    type Item = (#name, ::core::ops::Range<usize>);
+
+                    fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                        let bytes = self.haystack.as_bytes();
+                        let mut state = 0;
+                        let mut best: ::core::option::Option<(usize, usize, usize)> = ::core::option::Option::None;
+
+                        for end in self.position + 1..=bytes.len() {
+                            if best.is_some_and(|(start, _, _)| end > start + MAX_LENGTH) {
+                                break;
+                            }
+
+                            let byte = bytes[end - 1].to_ascii_lowercase();
+                            state = loop {
+                                let (edge_start, edge_end, fail, _, _) = STATES[state];
+                                let edges = &EDGES[edge_start as usize..edge_end as usize];
+                                if let ::core::result::Result::Ok(found) =
+                                    edges.binary_search_by_key(&byte, |(edge, _)| *edge)
+                                {
+                                    break edges[found].1 as usize;
+                                }
+                                if state == 0 {
+                                    break 0;
+                                }
+                                state = fail as usize;
+                            };
+
+                            let (_, _, _, output_start, output_end) = STATES[state];
+                            for pattern in &OUTPUTS[output_start as usize..output_end as usize] {
+                                let pattern = *pattern as usize;
+                                let start = end - PATTERNS[pattern].len();
+                                let better = best.is_none_or(|(best_start, best_end, _)| {
+                                    start < best_start || (start == best_start && end > best_end)
+                                });
+                                if better
+                                    && (self.case_insensitive || &bytes[start..end] == PATTERNS[pattern].as_bytes())
+                                    && (!self.word_boundary || self.is_boundary(start, end))
+                                {
+                                    best = ::core::option::Option::Some((start, end, pattern));
+                                }
+                            }
+                        }
+
+                        let (start, end, pattern) = best?;
+                        self.position = end;
+                        let variant = #variant_lookup;
+                        ::core::option::Option::Some((variant, start..end))
+                    }
+                }
+
+                Matches {
+                    haystack,
+                    position: 0,
+                    word_boundary,
+                    case_insensitive,
+                }
+            }
+        }
+    };
+
+    quote! {
+        /// Find the non-overlapping occurrences of unit variants' display strings in `haystack`,
+        /// preferring the leftmost and then the longest match
+        pub fn find_all(
+            haystack: &str,
+        ) -> impl ::core::iter::Iterator<Item = (Self, ::core::ops::Range<usize>)> + '_ {
+            Self::find_all_with(haystack, false, false)
+        }
+
+        /// Find occurrences of unit variants' display strings like `find_all`, optionally only those not adjacent to
+        /// alphanumeric characters or underscores, and optionally ignoring ASCII case
+        pub fn find_all_with(
+            haystack: &str,
+            word_boundary: bool,
+            case_insensitive: bool,
+        ) -> impl ::core::iter::Iterator<Item = (Self, ::core::ops::Range<usize>)> + '_ {
+            #body
+        }
+    }
+}
+
+/// Generates `as_cstr` for all-unit enums and `from_cstr` for every enum
+fn cstr_methods(
+    name: &Ident,
+    data: &syn::DataEnum,
+    variants: &[ParsedVariant],
+    table: bool,
+) -> Result<TokenStream2, Error> {
+    let mut methods = TokenStream2::new();
+
+    if has_only_unit_variants(data) && table {
+        methods.extend(quote! {
+            /// Get the display string of a variant as a NUL-terminated C string
+            pub fn as_cstr(&self) -> &'static ::core::ffi::CStr {
+                Self::ENUM2STR_CSTRINGS[self.variant_index()]
+            }
+        });
+    } else if has_only_unit_variants(data) {
+        let mut arms = TokenStream2::new();
+        for parsed in variants {
+            let variant_name = &parsed.variant.ident;
+            let value = c_string(&parsed.template(), parsed.variant.span())?;
+            arms.extend(quote_spanned! {
+                parsed.variant.span() =>
+                    #name::#variant_name => #value,
+            });
+        }
+
+        methods.extend(quote! {
+            /// Get the display string of a variant as a NUL-terminated C string
+            pub fn as_cstr(&self) -> &'static ::core::ffi::CStr {
+                match self {
+                    #arms
+                }
+            }
+        });
+    }
+
+    let parse_error_type = parse_error_type();
+    methods.extend(quote! {
+        /// Parse a variant from a C string holding one of its display strings
+        pub fn from_cstr(value: &::core::ffi::CStr) -> ::core::result::Result<Self, #parse_error_type> {
+            Self::from_bytes(value.to_bytes())
+        }
+    });
+
+    Ok(methods)
+}
+
+/// Generates an `extern "C"` function returning the display string of a discriminant
+fn ffi_function(
+    name: &Ident,
+    attrs: &[syn::Attribute],
+    data: &syn::DataEnum,
+    variants: &[ParsedVariant],
+) -> Result<TokenStream2, Error> {
+    if !has_only_unit_variants(data) {
+        return Err(Error::new_spanned(
+            name,
+            "#[enum2str(ffi)] requires an enum with only unit variants",
+        ));
+    }
+
+    let repr = repr_type(attrs).ok_or_else(|| {
+        Error::new_spanned(
+            name,
+            "#[enum2str(ffi)] requires a #[repr(C)] or integer #[repr(...)] attribute",
+        )
+    })?;
+
+    let function_name = Ident::new(
+        &format!(
+            "{}_as_cstr",
+            words(&name.to_string())
+                .iter()
+                .map(|word| word.to_lowercase())
+                .collect::<Vec<_>>()
+                .join("_")
+        ),
+        name.span(),
+    );
+
+    let mut checks = TokenStream2::new();
+    for parsed in variants {
+        let variant_name = &parsed.variant.ident;
+        let value = c_string(&parsed.template(), parsed.variant.span())?;
+        checks.extend(quote_spanned! {
+            parsed.variant.span() =>
+                if discriminant == #name::#variant_name as #repr {
+                    return #value.as_ptr();
+                }
+        });
+    }
+
+    let doc = format!(
+        " Get the display string of a `{}` discriminant as a NUL-terminated C string, or null for unknown discriminants",
+        name
+    );
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[allow(deprecated)]
+        #[unsafe(no_mangle)]
+        pub extern "C" fn #function_name(discriminant: #repr) -> *const ::core::ffi::c_char {
+            #checks
+            ::core::ptr::null()
+        }
+    })
+}
+
+#[cfg(feature = "log")]
+fn log_impls(name: &Ident, variants: &[ParsedVariant]) -> TokenStream2 {
+    let mut value_arms = TokenStream2::new();
+    let mut source_arms = TokenStream2::new();
+
+    for parsed in variants {
+        let pattern = parsed.argument_pattern(name);
+        let template = parsed.template();
+
+        if let Fields::Unit = parsed.variant.fields {
+            value_arms.extend(quote_spanned! {
+                parsed.variant.span() =>
+                    #pattern => ::log::kv::Value::from(#template),
+            });
+        }
+
+        let (bindings, keys): (Vec<_>, Vec<_>) = parsed.argument_fields().into_iter().unzip();
+        source_arms.extend(quote_spanned! {
+            parsed.variant.span() =>
+                #pattern => {
+                    visitor.visit_pair(
+                        ::log::kv::Key::from_str("template"),
+                        ::log::kv::Value::from(#template),
+                    )?;
+                    #(
+                        visitor.visit_pair(
+                            ::log::kv::Key::from_str(#keys),
+                            ::log::kv::Value::from_display(#bindings),
+                        )?;
+                    )*
+                }
+        });
+    }
+
+    let to_value = if variants
+        .iter()
+        .all(|parsed| matches!(parsed.variant.fields, Fields::Unit))
+    {
+        quote!(::log::kv::Value::from(self.as_str()))
+    } else {
+        quote! {
+            match self {
+                #value_arms
+                _ => ::log::kv::Value::from_display(self),
+            }
+        }
+    };
+
+    quote! {
+        #[allow(deprecated)]
+        impl ::log::kv::ToValue for #name {
+            fn to_value(&self) -> ::log::kv::Value<'_> {
+                #to_value
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::log::kv::Source for #name {
+            fn visit<'kvs>(
+                &'kvs self,
+                visitor: &mut dyn ::log::kv::VisitSource<'kvs>,
+            ) -> ::core::result::Result<(), ::log::kv::Error> {
+                match self {
+                    #source_arms
+                }
+                ::core::result::Result::Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+fn tracing_method(name: &Ident, data: &syn::DataEnum, variants: &[ParsedVariant]) -> TokenStream2 {
+    let record_arms = variants.iter().map(|parsed| {
+        let pattern = parsed.argument_pattern(name);
+        let template = parsed.template();
+        let (bindings, keys): (Vec<_>, Vec<_>) = parsed.argument_fields().into_iter().unzip();
+        quote_spanned! {
+            parsed.variant.span() =>
+                #pattern => {
+                    span.record("template", #template);
+                    #(
+                        span.record(#keys, ::tracing::field::display(#bindings));
+                    )*
+                }
+        }
+    });
+    let record_method = quote! {
+        /// Record the template and each key from `arguments()` as fields of `span`.
+        /// The span must declare these fields when it is created, e.g. with `template = tracing::field::Empty`.
+        pub fn record_tracing(&self, span: &::tracing::Span) {
+            match self {
+                #(#record_arms)*
+            }
+        }
+    };
+
+    let value_method = if has_only_unit_variants(data) {
+        quote! {
+            /// Get a `tracing` field value recording the display string as a `&'static str`
+            pub fn tracing_value(&self) -> &'static str {
+                self.as_str()
+            }
+        }
+    } else {
+        quote! {
+            /// Get a `tracing` field value recording the display string without allocating
+            pub fn tracing_value(&self) -> ::tracing::field::DisplayValue<&Self> {
+                ::tracing::field::display(self)
+            }
+        }
+    };
+
+    quote! {
+        #value_method
+        #record_method
+    }
+}
+
+/// Lowercases the words of a name and joins them with `-`, e.g. `HttpStatus` into `http-status`
+#[cfg(feature = "fluent")]
+fn kebab_case(value: &str) -> String {
+    words(value)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// The Fluent variable a template placeholder refers to, naming positional arguments `arg0`, `arg1`, ...
+#[cfg(feature = "fluent")]
+fn fluent_variable(argument: &str) -> String {
+    if argument.chars().all(|c| c.is_ascii_digit()) {
+        format!("arg{}", argument)
+    } else {
+        argument.to_string()
+    }
+}
+
+/// Escapes text for a Fluent pattern, continuing multi-line text on indented lines
+#[cfg(feature = "fluent")]
+fn fluent_text(text: &str) -> String {
+    text.replace('{', "{\"{\"}")
+        .replace('}', "{\"}\"}")
+        .replace('\n', "\n    ")
+}
+
+/// Renders a variant's template as a Fluent pattern, with placeholders as variables
+#[cfg(feature = "fluent")]
+fn fluent_pattern(parsed: &ParsedVariant) -> String {
+    let template = parsed.template();
+    let mut pattern = String::new();
+
+    if parsed.is_format_string() {
+        let mut position = 0;
+        for segment in template_segments(&template) {
+            match segment {
+                Segment::Text(text) => pattern.push_str(&fluent_text(&text)),
+                Segment::Placeholder(argument) => {
+                    let argument = if argument.is_empty() {
+                        position += 1;
+                        (position - 1).to_string()
+                    } else {
+                        argument
+                    };
+                    pattern.push_str(&format!("{{ ${} }}", fluent_variable(&argument)));
+                }
+            }
+        }
+    } else {
+        pattern = fluent_text(&template);
+    }
+
+    match pattern.strip_prefix(' ') {
+        _ if pattern.is_empty() => "{\"\"}".to_string(),
+        Some(rest) => format!("{{\" \"}}{}", rest),
+        None => pattern,
+    }
+}
+
+/// Generates the Fluent message ids, arguments, `localize` and the default `.ftl` source of an enum
+#[cfg(feature = "fluent")]
+fn fluent_methods(
+    name: &Ident,
+    variants: &[ParsedVariant],
+    prefix: Option<&LitStr>,
+) -> TokenStream2 {
+    let prefix = prefix
+        .map(LitStr::value)
+        .unwrap_or_else(|| kebab_case(&name.to_string()));
+
+    let mut id_arms = TokenStream2::new();
+    let mut argument_arms = TokenStream2::new();
+    let mut source = String::new();
+
+    for parsed in variants {
+        let variant_id = kebab_case(&parsed.variant.ident.to_string());
+        let id = if prefix.is_empty() {
+            variant_id
+        } else {
+            format!("{}-{}", prefix, variant_id)
+        };
+
+        let wildcard = parsed.wildcard_pattern(name);
+        id_arms.extend(quote_spanned! {
+            parsed.variant.span() =>
+                #wildcard => #id,
+        });
+
+        let pattern = parsed.argument_pattern(name);
+        let (bindings, keys): (Vec<_>, Vec<_>) = parsed
+            .argument_fields()
+            .into_iter()
+            .map(|(binding, key)| (binding, fluent_variable(&key)))
+            .unzip();
+        argument_arms.extend(quote_spanned! {
+            parsed.variant.span() =>
+                #pattern => {
+                    #(arguments.set(#keys, ::alloc::string::ToString::to_string(#bindings));)*
+                }
+        });
+
+        let doc = doc_comment(&parsed.variant.attrs);
+        for line in doc.lines() {
+            source.push_str(&format!("# {}\n", line).replace("# \n", "#\n"));
+        }
+        source.push_str(&format!("{} = {}\n", id, fluent_pattern(parsed)));
+    }
+
+    quote! {
+        /// Get the Fluent message id of a variant
+        pub const fn fluent_id(&self) -> &'static str {
+            match self {
+                #id_arms
+            }
+        }
+
+        /// Get the arguments of a variant as Fluent variables, naming positional arguments `arg0`, `arg1`, ...
+        pub fn fluent_args(&self) -> ::fluent_bundle::FluentArgs<'static> {
+            #[allow(unused_mut)]
+            let mut arguments = ::fluent_bundle::FluentArgs::new();
+            match self {
+                #argument_arms
+            }
+            arguments
+        }
+
+        /// Format a variant with its message from a Fluent bundle, or its display string when the bundle has none
+        pub fn localize<R, M>(
+            &self,
+            bundle: &::fluent_bundle::bundle::FluentBundle<R, M>,
+        ) -> ::alloc::string::String
+        where
+            R: ::core::borrow::Borrow<::fluent_bundle::FluentResource>,
+            M: ::fluent_bundle::memoizer::MemoizerKind,
+        {
+            let pattern = match bundle
+                .get_message(self.fluent_id())
+                .and_then(|message| message.value())
+            {
+                ::core::option::Option::Some(pattern) => pattern,
+                ::core::option::Option::None => return ::alloc::string::ToString::to_string(self),
+            };
+            let arguments = self.fluent_args();
+            let mut errors = ::alloc::vec::Vec::new();
+            bundle
+                .format_pattern(pattern, ::core::option::Option::Some(&arguments), &mut errors)
+                .into_owned()
+        }
+
+        /// Get a default Fluent `.ftl` source with a message per variant built from its template
+        pub const fn fluent_source() -> &'static str {
+            #source
+        }
+    }
+}
+
+#[proc_macro_derive(EnumStr, attributes(enum2str))]
+pub fn derive_enum2str(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    expand(&input).into()
 }
 
-#[cfg(feature = "try_from_string")]
-fn find_duplicate_strings(data: &syn::DataEnum) -> Vec<(String, Vec<String>)> {
-    let mut string_to_variants = std::collections::HashMap::new();
+/// Renders a gettext `.pot` catalog from the `gettext_entries()` of one or more enums deriving `EnumStr`
+/// with `#[enum2str(gettext)]`, e.g. `enum2str::gettext_pot!(Color, Status)`, as a `String`
+#[cfg(feature = "alloc")]
+#[proc_macro]
+pub fn gettext_pot(input: TokenStream) -> TokenStream {
+    let enums = parse_macro_input!(
+        input with syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated
+    );
 
-    for variant in data.variants.iter() {
-        if let Fields::Unit = variant.fields {
-            let mut string = variant.ident.to_string();
-            let variant_name = variant.ident.to_string();
+    if enums.is_empty() {
+        return derive_error!("Expected at least one enum, e.g. gettext_pot!(Color, Status)");
+    }
 
-            // Check for enum2str attribute
-            for attr in &variant.attrs {
-                if attr.path.is_ident("enum2str") {
-                    if let Ok(literal) = attr.parse_args::<syn::LitStr>() {
-                        string = literal.value();
+    let enums = enums.iter();
+    quote! {
+        {
+            fn quoted(value: &str) -> ::alloc::string::String {
+                let mut quoted = ::alloc::string::String::with_capacity(value.len() + 2);
+                quoted.push('"');
+                for character in value.chars() {
+                    match character {
+                        '\\' => quoted.push_str("\\\\"),
+                        '"' => quoted.push_str("\\\""),
+                        '\n' => quoted.push_str("\\n"),
+                        '\r' => quoted.push_str("\\r"),
+                        '\t' => quoted.push_str("\\t"),
+                        character => quoted.push(character),
                     }
                 }
+                quoted.push('"');
+                quoted
             }
 
-            string_to_variants
-                .entry(string)
-                .or_insert_with(Vec::new)
-                .push(variant_name);
+            let mut pot = ::alloc::string::String::from(
+                "msgid \"\"\nmsgstr \"\"\n\"Content-Type: text/plain; charset=UTF-8\\n\"\n\"Content-Transfer-Encoding: 8bit\\n\"\n",
+            );
+            for entries in [#(<#enums>::gettext_entries()),*] {
+                for &(context, id) in entries {
+                    pot.push_str("\nmsgctxt ");
+                    pot.push_str(&quoted(context));
+                    pot.push_str("\nmsgid ");
+                    pot.push_str(&quoted(id));
+                    pot.push_str("\nmsgstr \"\"\n");
+                }
+            }
+            pot
         }
     }
-
-    string_to_variants
-        .into_iter()
-        .filter(|(_, variants)| variants.len() > 1)
-        .collect()
+    .into()
 }
 
-#[proc_macro_derive(EnumStr, attributes(enum2str))]
-pub fn derive_enum2str(input: TokenStream) -> TokenStream {
-    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+/// Expands the derive for one enum, reporting errors as `compile_error!` invocations
+fn expand(input: &DeriveInput) -> TokenStream2 {
     let name = &input.ident;
 
-    let data = match input.data {
+    let data = match &input.data {
         Data::Enum(data) => data,
         _ => return derive_error!("enum2str only supports enums"),
     };
 
+    let options = match EnumOptions::parse(&input.attrs) {
+        Ok(options) => options,
+        Err(error) => return error.to_compile_error(),
+    };
+
     let mut match_arms = TokenStream2::new();
-    let mut variant_names = TokenStream2::new();
     let mut template_arms = TokenStream2::new();
     let mut arg_arms = TokenStream2::new();
-    let mut from_str_arms = TokenStream2::new();
 
-    for variant in data.variants.iter() {
+    let mut parsed_variants = match data
+        .variants
+        .iter()
+        .map(ParsedVariant::parse)
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(parsed_variants) => parsed_variants,
+        Err(error) => return error.to_compile_error(),
+    };
+
+    if let Err(error) = apply_reprs(&mut parsed_variants, &options) {
+        return error.to_compile_error();
+    }
+
+    for parsed in parsed_variants.iter() {
+        let variant = parsed.variant;
         let variant_name = &variant.ident;
 
         match &variant.fields {
             Fields::Unit => {
-                let mut display_ident = variant_name.to_string().to_token_stream();
-                let mut from_str_pattern = variant_name.to_string();
-
-                for attr in &variant.attrs {
-                    if attr.path.is_ident("enum2str") && attr.path.segments.first().is_some() {
-                        match attr.parse_args::<syn::LitStr>() {
-                            Ok(literal) => {
-                                display_ident = literal.to_token_stream();
-                                from_str_pattern = literal.value();
-                            }
-                            Err(_) => {
-                                return derive_error!(
-                                    r#"The 'enum2str' attribute is missing a String argument. Example: #[enum2str("Listening on: {} {}")] "#
-                                );
-                            }
-                        }
-                    }
-                }
+                let display_ident = match &parsed.literal {
+                    Some(literal) => literal.to_token_stream(),
+                    None => variant_name.to_string().to_token_stream(),
+                };
 
                 match_arms.extend(quote_spanned! {
                     variant.span() =>
                         #name::#variant_name =>  write!(f, "{}", #display_ident),
                 });
-
-                template_arms.extend(quote_spanned! {
-                    variant.span() =>
-                        #name::#variant_name => #display_ident.to_string(),
-                });
-
-                variant_names.extend(quote_spanned! {
-                    variant.span() =>
-                        stringify!(#variant_name).to_string(),
-                });
-
-                arg_arms.extend(quote_spanned! {
-                    variant.span() =>
-                        #name::#variant_name => vec![],
-                });
-
-                from_str_arms.extend(quote_spanned! {
-                    variant.span() =>
-                        s if s == #from_str_pattern => Ok(#name::#variant_name),
-                });
             }
             Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => {
-                let mut format_ident = "{}".to_string().to_token_stream();
-
-                for attr in &variant.attrs {
-                    if attr.path.is_ident("enum2str") && attr.path.segments.first().is_some() {
-                        match attr.parse_args::<LitStr>() {
-                            Ok(literal) => format_ident = literal.to_token_stream(),
-                            Err(_) => {
-                                return derive_error!(
-                                    r#"The 'enum2str' attribute is missing a String argument. Example: #[enum2str("Listening on: {} {}")] "#
-                                );
-                            }
-                        }
-                    }
-                }
+                let format_ident = match &parsed.literal {
+                    Some(literal) => literal.to_token_stream(),
+                    None => "{}".to_string().to_token_stream(),
+                };
 
                 if format_ident.to_string().contains("{}") {
                     let fields = unnamed.iter().len();
@@ -169,73 +3336,27 @@ pub fn derive_enum2str(input: TokenStream) -> TokenStream {
                         variant.span() =>
                             #name::#variant_name(#(#args),*) => write!(f, #format_ident, #(#args),*),
                     });
-
-                    template_arms.extend(quote_spanned! {
-                        variant.span() =>
-                            #name::#variant_name(..) => #format_ident.to_string(),
-                    });
-
-                    variant_names.extend(quote_spanned! {
-                        variant.span() =>
-                            stringify!(#variant_name).to_string(),
-                    });
-
-                    arg_arms.extend(quote_spanned! {
-                        variant.span() =>
-                            #name::#variant_name(#(#args),*) => vec![#(#args.to_string()),*],
-                    });
                 } else {
                     match_arms.extend(quote_spanned! {
                         variant.span() =>
                             #name::#variant_name(..) => write!(f, #format_ident),
                     });
-
-                    variant_names.extend(quote_spanned! {
-                        variant.span() =>
-                            stringify!(#variant_name).to_string(),
-                    });
-
-                    template_arms.extend(quote_spanned! {
-                        variant.span() =>
-                            #name::#variant_name(..) => #format_ident.to_string(),
-                    });
-
-                    arg_arms.extend(quote_spanned! {
-                        variant.span() =>
-                            #name::#variant_name(..) => vec![],
-                    });
                 }
             }
             Fields::Named(FieldsNamed { named, .. }) => {
                 let mut format_ident = variant_name.to_string().to_token_stream();
                 let mut field_idents = Vec::new();
 
-                let mut has_attribute = false;
-                for attr in &variant.attrs {
-                    if attr.path.is_ident("enum2str") {
-                        has_attribute = true;
-                        match attr.parse_args::<LitStr>() {
-                            Ok(literal) => {
-                                format_ident = literal.clone().to_token_stream();
-                                let literal_str = literal.value().clone();
-                                let mut start_indices =
-                                    literal_str.match_indices('{').map(|(i, _)| i);
-                                let mut end_indices =
-                                    literal_str.match_indices('}').map(|(i, _)| i);
-
-                                while let (Some(start), Some(end)) =
-                                    (start_indices.next(), end_indices.next())
-                                {
-                                    let field_name = &literal_str[(start + 1)..end];
-                                    field_idents.push(Ident::new(field_name, Span::call_site()));
-                                }
-                            }
-                            Err(_) => {
-                                return derive_error!(
-                                    r#"The 'enum2str' attribute is missing a String argument. Example: #[enum2str("Listening on: {} {}")] "#
-                                );
-                            }
-                        }
+                if let Some(literal) = &parsed.literal {
+                    format_ident = literal.to_token_stream();
+                    let literal_str = literal.value();
+                    let mut start_indices = literal_str.match_indices('{').map(|(i, _)| i);
+                    let mut end_indices = literal_str.match_indices('}').map(|(i, _)| i);
+
+                    while let (Some(start), Some(end)) = (start_indices.next(), end_indices.next())
+                    {
+                        let field_name = &literal_str[(start + 1)..end];
+                        field_idents.push(Ident::new(field_name, Span::call_site()));
                     }
                 }
 
@@ -252,176 +3373,557 @@ pub fn derive_enum2str(input: TokenStream) -> TokenStream {
                         variant.span() =>
                             #name::#variant_name { #(#field_names),* } => write!(f, #format_ident, #(#arg_pattern),*),
                     });
-
-                    arg_arms.extend(quote_spanned! {
-                        variant.span() =>
-                            #name::#variant_name { #(#field_names),* } => vec![#(#field_names.to_string()),*],
-                    });
                 } else {
                     // Just use variant name or custom string
                     match_arms.extend(quote_spanned! {
                         variant.span() =>
-                            #name::#variant_name { .. } => write!(f, "{}", if #has_attribute { #format_ident.to_string() } else { stringify!(#variant_name).to_string() }),
-                    });
-
-                    arg_arms.extend(quote_spanned! {
-                        variant.span() =>
-                            #name::#variant_name { .. } => vec![],
+                            #name::#variant_name { .. } => write!(f, "{}", #format_ident),
                     });
                 }
+            }
+        };
 
-                template_arms.extend(quote_spanned! {
-                    variant.span() =>
-                        #name::#variant_name { .. } => #format_ident.to_string(),
-                });
+        let wildcard_pattern = parsed.wildcard_pattern(name);
+        let template = parsed.template();
+        template_arms.extend(quote_spanned! {
+            variant.span() =>
+                #wildcard_pattern => #template,
+        });
 
-                variant_names.extend(quote_spanned! {
-                    variant.span() =>
-                        stringify!(#variant_name).to_string(),
-                });
+        let arg_pattern = parsed.argument_pattern(name);
+        let arg_bindings = parsed
+            .argument_fields()
+            .into_iter()
+            .map(|(binding, _)| binding);
+        arg_arms.extend(quote_spanned! {
+            variant.span() =>
+                #arg_pattern => ::alloc::vec![#(::alloc::string::ToString::to_string(#arg_bindings)),*],
+        });
+    }
 
-                if field_names.is_empty() && has_attribute {
-                    let display_str = format_ident.to_string();
-                    from_str_arms.extend(quote_spanned! {
-                        variant.span() =>
-                            s if s == #display_str => Ok(#name::#variant_name { }),
-                    });
-                }
+    let variant_names = parsed_variants.iter().enumerate().map(|(index, parsed)| {
+        let variant_name = parsed.variant.ident.to_string();
+        if options.table && parsed.template() == variant_name {
+            quote!(Self::ENUM2STR_STRINGS[#index])
+        } else {
+            quote!(#variant_name)
+        }
+    });
+    let parse_error_type = parse_error_type();
+
+    let typescript_methods = if options.typescript {
+        let (definition, const_definition) = typescript_definitions(name, &parsed_variants);
+        quote! {
+            /// Get a TypeScript string union type mirroring this enum's display strings
+            pub fn typescript_definition() -> &'static str {
+                #definition
+            }
+
+            /// Get a TypeScript const object of this enum's fixed display strings, followed by the union type
+            pub fn typescript_const_definition() -> &'static str {
+                #const_definition
+            }
+        }
+    } else {
+        TokenStream2::new()
+    };
+
+    let graphql_methods = if options.graphql {
+        let (sdl, values) = graphql_definitions(name, &input.attrs, &parsed_variants);
+        let values = values.iter().map(|(value, index)| {
+            let display = string_expr(name, &parsed_variants, *index, options.table);
+            quote!((#value, #display))
+        });
+        quote! {
+            /// Get a GraphQL enum type definition of this enum's unit variants
+            pub fn graphql_sdl() -> &'static str {
+                #sdl
+            }
+
+            /// Get pairs of GraphQL enum values and the display strings they stand for
+            pub fn graphql_values() -> &'static [(&'static str, &'static str)] {
+                const VALUES: &[(&str, &str)] = &[#(#values),*];
+                VALUES
+            }
+        }
+    } else {
+        TokenStream2::new()
+    };
+    let sql_methods = if options.sql {
+        if parsed_variants
+            .iter()
+            .all(|parsed| parsed.parse_string().is_none())
+        {
+            return Error::new_spanned(name, "#[enum2str(sql)] requires at least one unit variant")
+                .to_compile_error();
+        }
+        let values = sql_values(&parsed_variants);
+        quote! {
+            /// Get a SQL `CHECK` constraint restricting a column to the strings this enum parses from
+            pub fn sql_check_constraint(column: &str) -> ::alloc::string::String {
+                ::alloc::format!("CHECK ({} IN ({}))", column, #values)
+            }
+
+            /// Get a PostgreSQL `CREATE TYPE` statement for an enum type of the strings this enum parses from
+            pub fn postgres_create_type(name: &str) -> ::alloc::string::String {
+                ::alloc::format!("CREATE TYPE {} AS ENUM ({});", name, #values)
+            }
+        }
+    } else {
+        TokenStream2::new()
+    };
+    let gettext_methods = if options.gettext {
+        let entries = parsed_variants.iter().enumerate().map(|(index, parsed)| {
+            let context = format!("{}::{}", name, parsed.variant.ident);
+            let id = string_expr(name, &parsed_variants, index, options.table);
+            quote!((#context, #id))
+        });
+        quote! {
+            /// Get gettext catalog entries pairing each variant's `Enum::Variant` context with its template
+            pub fn gettext_entries() -> &'static [(&'static str, &'static str)] {
+                const ENTRIES: &[(&str, &str)] = &[#(#entries),*];
+                ENTRIES
+            }
+        }
+    } else {
+        TokenStream2::new()
+    };
+
+    let discriminant_type = has_explicit_discriminants(data)
+        .then(|| repr_type(&input.attrs).unwrap_or_else(|| quote!(isize)));
+
+    let numeric_parse = options.numeric.then(|| {
+        let (number_type, conversion) = match &discriminant_type {
+            Some(discriminant_type) => (
+                discriminant_type.clone(),
+                quote!(Self::enum2str_from_discriminant),
+            ),
+            None => {
+                let lookup = checked_index_lookup(&parsed_variants);
+                (quote!(usize), quote!(|index| #lookup))
             }
         };
+        quote! {
+            ::core::str::from_utf8(value)
+                .ok()
+                .and_then(|value| value.parse::<#number_type>().ok())
+                .and_then(#conversion)
+        }
+    });
+
+    let (bytes_methods, try_from_bytes_impl) =
+        bytes_methods(name, data, &parsed_variants, numeric_parse, options.table);
+
+    let ordinal_methods = if options.ordinal {
+        ordinal_methods(name, &parsed_variants, discriminant_type.as_ref())
+    } else {
+        TokenStream2::new()
+    };
+    let iteration_methods = if options.iter {
+        iteration_methods(&parsed_variants)
+    } else {
+        TokenStream2::new()
+    };
+
+    let mut lookups = TokenStream2::new();
+    if options.ordinal
+        || options.iter
+        || options.set
+        || options.numeric && discriminant_type.is_none()
+    {
+        lookups.extend(index_lookup(name, &parsed_variants, options.table));
+    }
+    if let Some(discriminant_type) = discriminant_type
+        .as_ref()
+        .filter(|_| options.ordinal || options.numeric)
+    {
+        lookups.extend(discriminant_lookup(
+            name,
+            &parsed_variants,
+            discriminant_type,
+        ));
     }
 
-    let expanded = quote! {
-        impl core::fmt::Display for #name {
-            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-                match self {
-                    #match_arms
-                }
+    let prefix_method = prefix_method(name, &parsed_variants, options.table);
+    let find_methods = if options.find {
+        find_methods(name, &parsed_variants, options.table)
+    } else {
+        TokenStream2::new()
+    };
+    let complete_methods = complete_methods(name, &parsed_variants, options.table);
+
+    let cstr_methods = if options.cstr {
+        match cstr_methods(name, data, &parsed_variants, options.table) {
+            Ok(methods) => methods,
+            Err(error) => return error.to_compile_error(),
+        }
+    } else {
+        TokenStream2::new()
+    };
+
+    #[cfg(feature = "tracing")]
+    let tracing_method = tracing_method(name, data, &parsed_variants);
+    #[cfg(not(feature = "tracing"))]
+    let tracing_method = TokenStream2::new();
+
+    let ffi_function = if options.ffi {
+        match ffi_function(name, &input.attrs, data, &parsed_variants) {
+            Ok(function) => function,
+            Err(error) => return error.to_compile_error(),
+        }
+    } else {
+        TokenStream2::new()
+    };
+
+    for (enabled, option) in [
+        (options.table, "table"),
+        (options.set, "set"),
+        (options.numeric, "numeric"),
+        (options.ordinal, "ordinal"),
+        (options.iter, "iter"),
+        (!options.reprs.is_empty(), "reprs(...)"),
+    ] {
+        if enabled && !has_only_unit_variants(data) {
+            let message = format!(
+                "#[enum2str({})] requires an enum with only unit variants",
+                option
+            );
+            return Error::new_spanned(name, message).to_compile_error();
+        }
+    }
+
+    let variant_index_body = if options.table && discriminant_type.is_none() {
+        quote!(*self as usize)
+    } else {
+        let index_arms = parsed_variants.iter().enumerate().map(|(index, parsed)| {
+            let pattern = parsed.wildcard_pattern(name);
+            quote_spanned!(parsed.variant.span() => #pattern => #index,)
+        });
+        quote! {
+            match self {
+                #(#index_arms)*
             }
         }
+    };
+    let copy_check = options.table.then(|| {
+        quote_spanned! {
+            name.span() =>
+                const _: () = {
+                    fn table_requires_copy<T: ::core::marker::Copy>() {}
+                    let _ = table_requires_copy::<#name>;
+                };
+        }
+    });
 
-        impl ::core::str::FromStr for #name {
-            type Err = ::alloc::string::String;
+    let info = if options.info {
+        info_items(name, &input.vis, &parsed_variants, options.table)
+    } else {
+        (TokenStream2::new(), TokenStream2::new())
+    };
+    let (info_const, info_types) = info;
 
-            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
-                match s {
-                    #from_str_arms
-                    _ => ::core::result::Result::Err(::alloc::format!("Invalid {} variant: {}", stringify!(#name), s))
+    let set_type = if options.set {
+        match set_type(
+            name,
+            &input.vis,
+            &parsed_variants,
+            options.separator.as_ref(),
+        ) {
+            Ok(set_type) => set_type,
+            Err(error) => return error.to_compile_error(),
+        }
+    } else {
+        TokenStream2::new()
+    };
+
+    let (display_body, string_lookup, arguments_body, table_items) = if options.table {
+        let count = parsed_variants.len();
+        let strings = if options.cstr {
+            let values = match parsed_variants
+                .iter()
+                .map(|parsed| c_string(&parsed.template(), parsed.variant.span()))
+                .collect::<Result<Vec<_>, _>>()
+            {
+                Ok(values) => values,
+                Err(error) => return error.to_compile_error(),
+            };
+            quote! {
+                const ENUM2STR_CSTRINGS: &'static [&'static ::core::ffi::CStr] = &[#(#values),*];
+
+                const ENUM2STR_STRINGS: &'static [&'static str] = &{
+                    let mut strings = [""; #count];
+                    let mut index = 0;
+                    while index < #count {
+                        strings[index] = match ::core::str::from_utf8(Self::ENUM2STR_CSTRINGS[index].to_bytes()) {
+                            ::core::result::Result::Ok(string) => string,
+                            ::core::result::Result::Err(_) => panic!("C string literals are UTF-8"),
+                        };
+                        index += 1;
+                    }
+                    strings
+                };
+            }
+        } else {
+            let templates = parsed_variants.iter().map(|parsed| parsed.template());
+            quote! {
+                const ENUM2STR_STRINGS: &'static [&'static str] = &[#(#templates),*];
+            }
+        };
+        let variants = parsed_variants.iter().map(|parsed| &parsed.variant.ident);
+        let mut sorted = (0..count).collect::<Vec<_>>();
+        sorted.sort_by_key(|index| parsed_variants[*index].template());
+        let expected = cfg!(feature = "alloc").then(|| {
+            let expected = expected_indices(&parsed_variants);
+            quote!(const ENUM2STR_EXPECTED: &'static [usize] = &[#(#expected),*];)
+        });
+
+        (
+            quote!(f.write_str(self.as_str())),
+            quote!(Self::ENUM2STR_STRINGS[self.variant_index()]),
+            quote!(::alloc::vec::Vec::new()),
+            quote! {
+                #strings
+
+                const ENUM2STR_VARIANTS: &'static [Self] = &[#(Self::#variants),*];
+
+                /// The indices of `ENUM2STR_STRINGS` sorted by string, then by declaration order
+                const ENUM2STR_SORTED: &'static [usize] = &[#(#sorted),*];
+
+                #expected
+            },
+        )
+    } else {
+        (
+            quote!(match self { #match_arms }),
+            quote!(match self { #template_arms }),
+            quote!(match self { #arg_arms }),
+            TokenStream2::new(),
+        )
+    };
+
+    let alloc_methods = if cfg!(feature = "alloc") {
+        let suggestions_method = suggestions_method(&parsed_variants, options.table);
+        quote! {
+            #suggestions_method
+
+            /// Parse a list of display strings separated by `separator`, ignoring whitespace around each one
+            pub fn parse_list(
+                s: &str,
+                separator: &str,
+            ) -> ::core::result::Result<::alloc::vec::Vec<Self>, #parse_error_type> {
+                if s.trim().is_empty() {
+                    return ::core::result::Result::Ok(::alloc::vec::Vec::new());
                 }
+                s.split(separator)
+                    .map(|item| item.trim().parse())
+                    .collect()
             }
-        }
 
-        impl #name {
             /// Get the names of this enum's variants
             pub fn variant_names() -> ::alloc::vec::Vec<::alloc::string::String> {
-                ::alloc::vec![
-                    #variant_names
-                ]
+                Self::VARIANT_NAMES
+                    .iter()
+                    .map(|name| ::alloc::string::ToString::to_string(*name))
+                    .collect()
             }
 
             /// Get the format specifier used to display a variant
             pub fn template(&self) -> ::alloc::string::String {
-                match self {
-                    #template_arms
-                }
+                ::alloc::string::ToString::to_string(#string_lookup)
             }
 
             /// Gets the names of a variant's arguments
             pub fn arguments(&self) -> ::alloc::vec::Vec<::alloc::string::String> {
-                match self {
-                    #arg_arms
-                }
+                #arguments_body
+            }
+        }
+    } else {
+        quote! {
+            /// Get the names of this enum's variants
+            pub fn variant_names() -> &'static [&'static str] {
+                Self::VARIANT_NAMES
+            }
+
+            /// Get the format specifier used to display a variant
+            pub fn template(&self) -> &'static str {
+                #string_lookup
+            }
+        }
+    };
+
+    let as_str_method = if has_only_unit_variants(data) {
+        quote! {
+            /// Get the display string of a variant
+            pub fn as_str(&self) -> &'static str {
+                #string_lookup
+            }
+        }
+    } else {
+        TokenStream2::new()
+    };
+
+    let expanded = quote! {
+        #[allow(deprecated)]
+        impl core::fmt::Display for #name {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                #display_body
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::str::FromStr for #name {
+            type Err = #parse_error_type;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                Self::from_bytes(s.as_bytes())
+            }
+        }
+
+        #[allow(deprecated)]
+        impl #name {
+            /// The names of this enum's variants
+            pub const VARIANT_NAMES: &'static [&'static str] = &[#(#variant_names),*];
+
+            #table_items
+
+            /// Get the position of a variant in declaration order
+            pub fn variant_index(&self) -> usize {
+                #variant_index_body
             }
+
+            /// Get the name of a variant as declared
+            pub fn variant_name(&self) -> &'static str {
+                Self::VARIANT_NAMES[self.variant_index()]
+            }
+
+            #info_const
+
+            #lookups
+
+            #ordinal_methods
+
+            #iteration_methods
+
+            #alloc_methods
+
+            #as_str_method
+
+            #typescript_methods
+
+            #graphql_methods
+
+            #sql_methods
+
+            #gettext_methods
+
+            #bytes_methods
+
+            #prefix_method
+
+            #find_methods
+
+            #complete_methods
+
+            #cstr_methods
+
+            #tracing_method
         }
+
+        #[allow(deprecated)]
+        #try_from_bytes_impl
+
+        #ffi_function
+
+        #copy_check
+
+        #set_type
+
+        #info_types
     };
 
     #[allow(unused_mut)]
-    let mut expanded = TokenStream::from(expanded);
+    let mut expanded = expanded;
 
-    #[cfg(feature = "try_from_string")]
-    if has_only_unit_variants(&data) {
-        let duplicates = find_duplicate_strings(&data);
-        let has_error_variant = data.variants.iter().any(|v| v.ident == "Error");
-
-        let try_from_impl = if has_error_variant {
-            if duplicates.is_empty() {
-                quote! {
-                    impl std::convert::TryFrom<std::string::String> for #name {
-                        type Error = std::string::String;
-
-                        fn try_from(value: std::string::String) -> Result<Self, std::string::String> {
-                            use core::str::FromStr;
-                            FromStr::from_str(&value)
-                        }
-                    }
-                }
-            } else {
-                let error_msg = format!(
-                    "Ambiguous string representation. The following strings are used by multiple variants: {}",
-                    duplicates
-                        .iter()
-                        .map(|(s, v)| format!("'{}' (used by {})", s, v.join(", ")))
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                );
+    #[cfg(feature = "rusqlite")]
+    expanded.extend(rusqlite_impls(name));
 
-                let duplicate_strings: Vec<_> = duplicates.iter().map(|(s, _)| s).collect();
+    #[cfg(feature = "log")]
+    expanded.extend(log_impls(name, &parsed_variants));
 
-                quote! {
-                    impl std::convert::TryFrom<std::string::String> for #name {
-                        type Error = std::string::String;
+    #[cfg(feature = "try_from_string")]
+    expanded.extend(try_from_impls(name, &parsed_variants));
 
-                        fn try_from(value: std::string::String) -> Result<Self, std::string::String> {
-                            use core::str::FromStr;
-                            if [#(#duplicate_strings),*].contains(&value.as_str()) {
-                                return Err(#error_msg.to_string());
-                            }
-                            FromStr::from_str(&value)
-                        }
-                    }
-                }
+    #[cfg(feature = "fluent")]
+    {
+        let methods = fluent_methods(name, &parsed_variants, options.fluent_prefix.as_ref());
+        expanded.extend(quote! {
+            #[allow(deprecated)]
+            impl #name {
+                #methods
             }
-        } else {
-            if duplicates.is_empty() {
-                quote! {
-                    impl std::convert::TryFrom<std::string::String> for #name {
-                        type Error = std::string::String;
-
-                        fn try_from(value: std::string::String) -> Result<Self, Self::Error> {
-                            use core::str::FromStr;
-                            Self::from_str(&value)
-                        }
-                    }
+        });
+    }
+
+    if parsed_variants
+        .iter()
+        .any(|parsed| !parsed.locales.is_empty())
+    {
+        match locale_method(name, &parsed_variants) {
+            Ok(method) => expanded.extend(quote! {
+                #[allow(deprecated)]
+                impl #name {
+                    #method
                 }
-            } else {
-                let error_msg = format!(
-                    "Ambiguous string representation. The following strings are used by multiple variants: {}",
-                    duplicates
-                        .iter()
-                        .map(|(s, v)| format!("'{}' (used by {})", s, v.join(", ")))
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                );
+            }),
+            Err(error) => return error.to_compile_error(),
+        }
+    }
 
-                let duplicate_strings: Vec<_> = duplicates.iter().map(|(s, _)| s).collect();
+    if !options.reprs.is_empty() {
+        let (methods, types) = repr_items(name, &input.vis, &parsed_variants, &options.reprs);
+        expanded.extend(quote! {
+            #[allow(deprecated)]
+            impl #name {
+                #methods
+            }
 
-                quote! {
-                    impl std::convert::TryFrom<std::string::String> for #name {
-                        type Error = std::string::String;
+            #types
+        });
+    }
 
-                        fn try_from(value: std::string::String) -> Result<Self, Self::Error> {
-                            use core::str::FromStr;
-                            if [#(#duplicate_strings),*].contains(&value.as_str()) {
-                                return Err(#error_msg.to_string());
-                            }
-                            Self::from_str(&value)
-                        }
-                    }
-                }
+    if parsed_variants
+        .iter()
+        .any(|parsed| !parsed.groups.is_empty())
+    {
+        let group_methods = group_methods(name, data, &parsed_variants);
+        expanded.extend(quote! {
+            #[allow(deprecated)]
+            impl #name {
+                #group_methods
             }
-        };
+        });
+    }
+
+    if !options.required_props.is_empty()
+        || parsed_variants
+            .iter()
+            .any(|parsed| !parsed.props.is_empty())
+    {
+        match prop_items(name, &input.vis, &parsed_variants, &options.required_props) {
+            Ok((methods, types)) => expanded.extend(quote! {
+                #[allow(deprecated)]
+                impl #name {
+                    #methods
+                }
+
+                #types
+            }),
+            Err(error) => return error.to_compile_error(),
+        }
+    }
 
-        expanded.extend(TokenStream::from(try_from_impl));
+    if let Some(kind) = &options.kind {
+        expanded.extend(kind_items(name, &input.vis, &parsed_variants, kind));
     }
 
     expanded
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 extern crate alloc;
 use enum2str::EnumStr;
 
@@ -219,10 +221,32 @@ fn test_from_str() {
     assert!(Color::from_str("NotAColor").is_err());
 }
 
+#[derive(EnumStr, Debug, PartialEq)]
+enum Marker {
+    #[enum2str("Cleared")]
+    Clear {},
+    Pending {},
+    Placed {
+        _at: u8,
+    },
+}
+
+#[test]
+fn fieldless_brace_from_str() {
+    assert_eq!("Cleared".parse::<Marker>().unwrap(), Marker::Clear {});
+    assert_eq!("Pending".parse::<Marker>().unwrap(), Marker::Pending {});
+    assert!("Placed".parse::<Marker>().is_err());
+    assert_eq!(Marker::Placed { _at: 1 }.to_string(), "Placed");
+    assert_eq!(
+        Marker::parse_prefix("Cleared!"),
+        Some((Marker::Clear {}, "!"))
+    );
+}
+
 #[cfg(feature = "try_from_string")]
 #[test]
 fn test_try_from_string() {
-    use std::{convert::TryFrom, str::FromStr};
+    use std::convert::TryFrom;
 
     // Test with a unit-only enum
     #[derive(EnumStr, Debug, PartialEq)]
@@ -274,3 +298,1250 @@ fn test_try_from_string_duplicates() {
     assert!(err.contains("Ambiguous string representation"));
     assert!(err.contains("'Same' (used by One, Two)"));
 }
+
+#[allow(dead_code)]
+#[derive(EnumStr, Debug, PartialEq)]
+#[enum2str(typescript, graphql, sql, cstr, find, gettext, info)]
+enum Swatch {
+    Green,
+    #[enum2str("Burgundy")]
+    #[enum2str(group = "warm")]
+    Red,
+    Blue {
+        hue: u8,
+    },
+    #[enum2str("Unique - {label}_{id}")]
+    Unique {
+        id: u8,
+        label: String,
+    },
+    Named(String),
+    #[enum2str("Color: {}. Shape: {}.")]
+    Complex(u8, u8),
+}
+
+#[test]
+fn typescript_definition() {
+    assert_eq!(
+        Swatch::typescript_definition(),
+        "export type Swatch = \"Green\" | \"Burgundy\" | \"Blue\" | `Unique - ${string}_${string}` | `${string}` | `Color: ${string}. Shape: ${string}.`;\n"
+    );
+}
+
+#[test]
+fn typescript_const_definition() {
+    assert_eq!(
+        Swatch::typescript_const_definition(),
+        concat!(
+            "export const Swatch = {\n",
+            "  Green: \"Green\",\n",
+            "  Red: \"Burgundy\",\n",
+            "  Blue: \"Blue\",\n",
+            "} as const;\n",
+            "export type Swatch = \"Green\" | \"Burgundy\" | \"Blue\" | `Unique - ${string}_${string}` | `${string}` | `Color: ${string}. Shape: ${string}.`;\n",
+        )
+    );
+}
+
+/// Progress of a submitted job
+#[derive(EnumStr, Debug, PartialEq)]
+#[enum2str(kind = "StatusKind", graphql, sql, cstr, ordinal, iter)]
+enum Status {
+    /// Waiting to be scheduled
+    #[enum2str(group = "active")]
+    Pending,
+
+    #[enum2str("In Progress", group = "active")]
+    InProgress,
+
+    #[deprecated(note = "Use \"Done\" instead")]
+    #[enum2str(group = "terminal", group = "legacy")]
+    Finished,
+
+    #[enum2str(group = "terminal")]
+    Done,
+}
+
+#[test]
+fn graphql_sdl() {
+    assert_eq!(
+        Status::graphql_sdl(),
+        concat!(
+            "\"Progress of a submitted job\"\n",
+            "enum Status {\n",
+            "  \"Waiting to be scheduled\"\n",
+            "  PENDING\n",
+            "  IN_PROGRESS\n",
+            "  FINISHED @deprecated(reason: \"Use \\\"Done\\\" instead\")\n",
+            "  DONE\n",
+            "}\n",
+        )
+    );
+    assert_eq!(
+        Swatch::graphql_sdl(),
+        "enum Swatch {\n  GREEN\n  BURGUNDY\n}\n"
+    );
+}
+
+#[derive(EnumStr)]
+#[enum2str(graphql, sql, cstr)]
+enum Casing {
+    #[enum2str("Custom Color")]
+    Spaced,
+    #[enum2str("custom-color")]
+    Kebab,
+    #[enum2str("Custom Color")]
+    Repeated,
+    #[enum2str("Café")]
+    Cafe,
+    #[enum2str("CAF")]
+    Shouted,
+}
+
+#[test]
+fn graphql_colliding_values() {
+    assert_eq!(
+        Casing::graphql_sdl(),
+        "enum Casing {\n  CUSTOM_COLOR\n  CUSTOM_COLOR_2\n  CUSTOM_COLOR_3\n  CAF\n  CAF_2\n}\n"
+    );
+    assert_eq!(
+        Casing::graphql_values(),
+        &[
+            ("CUSTOM_COLOR", "Custom Color"),
+            ("CUSTOM_COLOR_2", "custom-color"),
+            ("CUSTOM_COLOR_3", "Custom Color"),
+            ("CAF", "Café"),
+            ("CAF_2", "CAF"),
+        ]
+    );
+}
+
+#[test]
+fn graphql_values() {
+    assert_eq!(
+        Status::graphql_values(),
+        &[
+            ("PENDING", "Pending"),
+            ("IN_PROGRESS", "In Progress"),
+            ("FINISHED", "Finished"),
+            ("DONE", "Done"),
+        ]
+    );
+}
+
+#[test]
+fn sql_check_constraint() {
+    assert_eq!(
+        Swatch::sql_check_constraint("color"),
+        "CHECK (color IN ('Green', 'Burgundy'))"
+    );
+}
+
+#[test]
+fn postgres_create_type() {
+    assert_eq!(
+        Status::postgres_create_type("status"),
+        "CREATE TYPE status AS ENUM ('Pending', 'In Progress', 'Finished', 'Done');"
+    );
+    assert_eq!(
+        Casing::postgres_create_type("casing"),
+        "CREATE TYPE casing AS ENUM ('Custom Color', 'custom-color', 'Café', 'CAF');"
+    );
+}
+
+#[derive(EnumStr, Debug, PartialEq)]
+#[enum2str(sql)]
+enum Flag {
+    On,
+    #[enum2str("")]
+    Unset,
+}
+
+#[test]
+fn sql_check_constraint_empty_string() {
+    assert_eq!(Flag::sql_check_constraint("f"), "CHECK (f IN ('On', ''))");
+    assert_eq!("".parse::<Flag>().unwrap(), Flag::Unset);
+    assert_eq!(Flag::On.to_string(), "On");
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn rusqlite_round_trip() {
+    let connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute(
+            &format!(
+                "CREATE TABLE jobs (status TEXT NOT NULL {})",
+                Status::sql_check_constraint("status")
+            ),
+            (),
+        )
+        .unwrap();
+    connection
+        .execute(
+            "INSERT INTO jobs (status) VALUES (?1)",
+            (Status::InProgress,),
+        )
+        .unwrap();
+
+    let stored: String = connection
+        .query_row("SELECT status FROM jobs", (), |row| row.get(0))
+        .unwrap();
+    assert_eq!(stored, "In Progress");
+
+    let status: Status = connection
+        .query_row("SELECT status FROM jobs", (), |row| row.get(0))
+        .unwrap();
+    assert_eq!(status, Status::InProgress);
+
+    assert!(
+        connection
+            .execute("INSERT INTO jobs (status) VALUES ('Cancelled')", ())
+            .is_err()
+    );
+    assert!(
+        connection
+            .query_row("SELECT 'Cancelled'", (), |row| row.get::<_, Status>(0))
+            .is_err()
+    );
+}
+
+#[derive(EnumStr, Debug, PartialEq)]
+#[enum2str(ffi, cstr, ordinal)]
+#[repr(u8)]
+enum Channel {
+    Red = 1,
+    #[enum2str("Alpha channel")]
+    Alpha = 4,
+}
+
+#[test]
+fn as_cstr() {
+    assert_eq!(Status::InProgress.as_cstr(), c"In Progress");
+    assert_eq!(Channel::Alpha.as_cstr(), c"Alpha channel");
+}
+
+#[test]
+fn from_cstr() {
+    assert_eq!(
+        Status::from_cstr(c"In Progress").unwrap(),
+        Status::InProgress
+    );
+    assert_eq!(Swatch::from_cstr(c"Burgundy").unwrap(), Swatch::Red);
+    assert_eq!(
+        Swatch::from_cstr(c"Unique").unwrap_err(),
+        "Invalid Swatch variant: Unique; expected one of Green, Burgundy"
+    );
+}
+
+#[test]
+fn from_cstr_shared_string() {
+    assert!(matches!(
+        Casing::from_cstr(c"Custom Color"),
+        Ok(Casing::Spaced)
+    ));
+}
+
+#[derive(EnumStr)]
+enum Terminated {
+    #[enum2str("end\0")]
+    End,
+}
+
+#[test]
+fn nul_in_display_without_cstr() {
+    assert_eq!(Terminated::End.as_bytes(), b"end\0");
+}
+
+#[test]
+fn as_bytes() {
+    assert_eq!(Status::InProgress.as_bytes(), b"In Progress");
+}
+
+#[test]
+fn from_bytes() {
+    use std::convert::TryFrom;
+
+    assert_eq!(Status::from_bytes(b"Pending").unwrap(), Status::Pending);
+    assert_eq!(Color::try_from(&b"Burgundy"[..]).unwrap(), Color::Red);
+    assert_eq!(
+        Color::from_bytes(b"Red\xff").unwrap_err(),
+        "Invalid Color variant: Red\u{fffd}; expected one of Green, Burgundy"
+    );
+}
+
+#[derive(EnumStr, Debug, PartialEq, Clone, Copy)]
+enum Element {
+    Hydrogen,
+    Helium,
+    Lithium,
+    Beryllium,
+    Boron,
+    Carbon,
+    Nitrogen,
+    Oxygen,
+    Fluorine,
+    Neon,
+    Sodium,
+    Magnesium,
+    Aluminium,
+    Silicon,
+    Phosphorus,
+    Sulfur,
+    Chlorine,
+    Argon,
+    #[enum2str("Argon")]
+    Noble,
+    #[enum2str("")]
+    Unknown,
+}
+
+#[test]
+fn from_bytes_large_enum() {
+    use Element::*;
+
+    for element in [
+        Hydrogen, Helium, Lithium, Beryllium, Boron, Carbon, Nitrogen, Oxygen, Fluorine, Neon,
+        Sodium, Magnesium, Aluminium, Silicon, Phosphorus, Sulfur, Chlorine, Argon, Unknown,
+    ] {
+        assert_eq!(element.to_string().parse::<Element>().unwrap(), element);
+    }
+    assert_eq!(Element::from_bytes(b"Argon").unwrap(), Argon);
+    assert_eq!(Noble.to_string(), "Argon");
+    assert!(Element::from_bytes(b"Boro").is_err());
+    assert!(Element::from_bytes(b"Boronn").is_err());
+    assert!(Element::from_bytes(b"Carbox").is_err());
+    assert!(Element::from_bytes(b"Xenon").is_err());
+}
+
+#[derive(EnumStr, Debug, PartialEq, Clone, Copy)]
+#[enum2str(table, numeric, cstr, ordinal)]
+enum Opcode {
+    #[enum2str("nop")]
+    Nop = 0x90,
+    #[enum2str("mov")]
+    Move = 0x88,
+    Halt = 0xf4,
+}
+
+#[test]
+fn table_lookup() {
+    assert_eq!(Opcode::Move.to_string(), "mov");
+    assert_eq!(Opcode::Nop.as_str(), "nop");
+    assert_eq!(Opcode::Halt.template(), "Halt");
+    assert!(Opcode::Move.arguments().is_empty());
+    assert_eq!(Opcode::Move.as_bytes(), b"mov");
+    assert_eq!(Opcode::Nop.as_cstr(), c"nop");
+    assert_eq!("mov".parse::<Opcode>().unwrap(), Opcode::Move);
+    assert_eq!(Opcode::VARIANT_NAMES, &["Nop", "Move", "Halt"]);
+}
+
+#[derive(EnumStr, Debug, PartialEq, Clone, Copy)]
+#[enum2str(table, cstr, find, iter, ordinal, gettext, graphql)]
+enum Mnemonic {
+    #[enum2str("ld")]
+    Load,
+    #[enum2str("ldi")]
+    LoadImmediate,
+    #[enum2str("ld")]
+    LoadAlias,
+    Halt,
+    #[enum2str("jmp", skip)]
+    Jump,
+}
+
+#[test]
+fn table_shared_strings() {
+    assert_eq!(Mnemonic::LoadImmediate.as_cstr(), c"ldi");
+    assert_eq!(Mnemonic::Halt.as_str(), "Halt");
+    assert_eq!(Mnemonic::Halt.variant_index(), 3);
+    assert_eq!(
+        Mnemonic::VARIANT_NAMES,
+        &["Load", "LoadImmediate", "LoadAlias", "Halt", "Jump"]
+    );
+    assert_eq!("ld".parse::<Mnemonic>().unwrap(), Mnemonic::Load);
+    assert_eq!("jmp".parse::<Mnemonic>().unwrap(), Mnemonic::Jump);
+    assert_eq!(
+        "lx".parse::<Mnemonic>().unwrap_err(),
+        "Invalid Mnemonic variant: lx; expected one of ld, ldi, Halt, jmp; did you mean \"ld\"?"
+    );
+    assert_eq!(
+        Mnemonic::parse_prefix("ldi r1"),
+        Some((Mnemonic::LoadImmediate, " r1"))
+    );
+    assert_eq!(
+        Mnemonic::parse_prefix("ld r1"),
+        Some((Mnemonic::Load, " r1"))
+    );
+    assert_eq!(Mnemonic::parse_prefix("x"), None);
+    assert_eq!(
+        Mnemonic::complete("").collect::<Vec<_>>(),
+        vec!["Halt", "ld", "ldi"]
+    );
+    assert_eq!(
+        Mnemonic::find_all("ld x; ldi y; Halt").collect::<Vec<_>>(),
+        vec![
+            (Mnemonic::Load, 0..2),
+            (Mnemonic::LoadImmediate, 6..9),
+            (Mnemonic::Halt, 13..17),
+        ]
+    );
+    assert_eq!(Mnemonic::iter().count(), 4);
+    assert_eq!(Mnemonic::from_index(4), Some(Mnemonic::Jump));
+    assert_eq!(Mnemonic::from_index(5), None);
+    assert_eq!(
+        Mnemonic::gettext_entries()[2],
+        ("Mnemonic::LoadAlias", "ld")
+    );
+    assert_eq!(
+        Mnemonic::graphql_values()[..3],
+        [("LD", "ld"), ("LDI", "ldi"), ("LD_2", "ld")]
+    );
+}
+
+#[derive(EnumStr, Debug, PartialEq)]
+enum Op {
+    #[enum2str("<")]
+    Lt,
+    #[enum2str("<=")]
+    Le,
+    #[enum2str("<<=")]
+    ShlAssign,
+    #[enum2str("=")]
+    Assign,
+    Not(bool),
+}
+
+#[test]
+fn parse_prefix() {
+    assert_eq!(Op::parse_prefix("<=x"), Some((Op::Le, "x")));
+    assert_eq!(Op::parse_prefix("< x"), Some((Op::Lt, " x")));
+    assert_eq!(Op::parse_prefix("<<=1"), Some((Op::ShlAssign, "1")));
+    assert_eq!(Op::parse_prefix("<<1"), Some((Op::Lt, "<1")));
+    assert_eq!(Op::parse_prefix("="), Some((Op::Assign, "")));
+    assert_eq!(Op::parse_prefix("!x"), None);
+    assert_eq!(Op::parse_prefix(""), None);
+    assert_eq!(Op::Not(true).to_string(), "true");
+}
+
+#[derive(EnumStr, Debug, PartialEq)]
+#[enum2str(find)]
+enum Level {
+    #[enum2str("warn")]
+    Warn,
+    #[enum2str("warning")]
+    Warning,
+    #[enum2str("error")]
+    Error,
+    #[enum2str("err")]
+    Failure,
+    #[enum2str("")]
+    Empty,
+    Code(u16),
+}
+
+#[test]
+fn find_all() {
+    let found = Level::find_all("warning: error in warn_count, err").collect::<Vec<_>>();
+    assert_eq!(
+        found,
+        vec![
+            (Level::Warning, 0..7),
+            (Level::Error, 9..14),
+            (Level::Warn, 18..22),
+            (Level::Failure, 30..33),
+        ]
+    );
+    assert_eq!(Level::find_all("nothing here").count(), 0);
+    assert_eq!(Level::find_all("ERROR").count(), 0);
+    assert_eq!(Level::Code(404).to_string(), "404");
+}
+
+#[test]
+fn find_all_with_options() {
+    let found =
+        Level::find_all_with("Warning: errors in warn_count, ERR", true, true).collect::<Vec<_>>();
+    assert_eq!(
+        found,
+        vec![(Level::Warning, 0..7), (Level::Failure, 31..34)]
+    );
+    assert_eq!(
+        Level::find_all_with("é-warn-é", true, false).collect::<Vec<_>>(),
+        vec![(Level::Warn, 3..7)]
+    );
+    assert_eq!(
+        Swatch::find_all("Burgundy").collect::<Vec<_>>(),
+        vec![(Swatch::Red, 0..8)]
+    );
+}
+
+#[derive(EnumStr)]
+enum Command {
+    /// Show the help text
+    Help,
+    /// Print the history
+    History,
+    #[enum2str("hide")]
+    Hide,
+    #[enum2str(skip)]
+    Hidden,
+    #[enum2str("Help")]
+    Assist,
+    Quit,
+    Run(String),
+}
+
+#[test]
+fn complete() {
+    assert_eq!(
+        Command::complete("H").collect::<Vec<_>>(),
+        vec!["Help", "History"]
+    );
+    assert_eq!(Command::complete("Qu").collect::<Vec<_>>(), vec!["Quit"]);
+    assert_eq!(Command::complete("x").count(), 0);
+    assert_eq!(Command::complete("").count(), 4);
+}
+
+#[test]
+fn complete_with_options() {
+    assert_eq!(
+        Command::complete_with("hi", true).collect::<Vec<_>>(),
+        vec!["History", "hide"]
+    );
+    assert_eq!(
+        Command::complete_described("H", false).collect::<Vec<_>>(),
+        vec![
+            ("Help", Some("Show the help text")),
+            ("History", Some("Print the history")),
+        ]
+    );
+    assert_eq!(
+        Command::complete_described("Q", false).collect::<Vec<_>>(),
+        vec![("Quit", None)]
+    );
+    assert_eq!(Command::Run("ls".to_string()).to_string(), "ls");
+    assert_eq!(Command::Assist.to_string(), "Help");
+}
+
+#[test]
+fn parse_error_suggestion() {
+    assert_eq!(
+        "Burgandy".parse::<Color>().unwrap_err(),
+        "Invalid Color variant: Burgandy; expected one of Green, Burgundy; did you mean \"Burgundy\"?"
+    );
+    assert_eq!(
+        "green".parse::<Color>().unwrap_err(),
+        "Invalid Color variant: green; expected one of Green, Burgundy; did you mean \"Green\"?"
+    );
+    assert_eq!(
+        "Square".parse::<SpecialEnum>().unwrap_err(),
+        "Invalid SpecialEnum variant: Square"
+    );
+}
+
+#[test]
+fn suggestions() {
+    assert_eq!(Level::suggestions("eror"), vec!["error", "err"]);
+    assert_eq!(Level::suggestions("warnig"), vec!["warning"]);
+    assert!(Color::suggestions("Purple").is_empty());
+}
+
+#[derive(EnumStr, Debug, PartialEq)]
+#[enum2str(set, separator = "|")]
+#[enum2str(numeric, ordinal)]
+enum Permission {
+    #[enum2str("read")]
+    Read,
+    #[enum2str("write")]
+    Write,
+    #[enum2str("exec")]
+    Execute,
+}
+
+#[test]
+fn parse_list() {
+    assert_eq!(
+        Permission::parse_list("read, exec", ",").unwrap(),
+        vec![Permission::Read, Permission::Execute]
+    );
+    assert_eq!(
+        Status::parse_list("Done|In Progress", "|").unwrap(),
+        vec![Status::Done, Status::InProgress]
+    );
+    assert!(Permission::parse_list("  ", ",").unwrap().is_empty());
+    assert!(Permission::parse_list("read,,write", ",").is_err());
+}
+
+#[test]
+fn set_operations() {
+    let mut set = PermissionSet::new();
+    assert!(set.is_empty());
+    assert!(set.insert(Permission::Execute));
+    assert!(!set.insert(Permission::Execute));
+    set.extend([Permission::Read]);
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&Permission::Read));
+    assert!(!set.contains(&Permission::Write));
+
+    let write = PermissionSet::from(Permission::Write);
+    assert_eq!(set | write, PermissionSet::all());
+    assert!((set & write).is_empty());
+    assert_eq!(PermissionSet::all() - write, set);
+    assert_eq!((set ^ PermissionSet::all()), write);
+    assert!(set.is_subset(&PermissionSet::all()));
+    assert!(set.remove(&Permission::Read));
+    assert!(!set.remove(&Permission::Read));
+    assert_eq!(set.iter().collect::<Vec<_>>(), vec![Permission::Execute]);
+}
+
+#[test]
+fn set_display_and_parse() {
+    let set = [Permission::Execute, Permission::Read]
+        .into_iter()
+        .collect::<PermissionSet>();
+    assert_eq!(set.to_string(), "read|exec");
+    assert_eq!(format!("{:?}", set), r#"{"read", "exec"}"#);
+    assert_eq!("exec | read".parse::<PermissionSet>().unwrap(), set);
+    assert_eq!("".parse::<PermissionSet>().unwrap(), PermissionSet::new());
+    assert!("read,exec".parse::<PermissionSet>().is_err());
+}
+
+#[test]
+fn variant_name_and_index() {
+    assert_eq!(Color::Red.variant_name(), "Red");
+    assert_eq!(Color::Red.variant_index(), 1);
+    let unique = Color::Unique {
+        id: 1,
+        label: "x".to_string(),
+    };
+    assert_eq!(unique.variant_name(), "Unique");
+    assert_eq!(unique.variant_index(), 4);
+    assert_eq!(Status::InProgress.variant_name(), "InProgress");
+}
+
+#[test]
+fn variant_info() {
+    assert_eq!(Swatch::VARIANT_INFO.len(), 6);
+    assert_eq!(
+        Swatch::VARIANT_INFO[1],
+        SwatchVariantInfo {
+            name: "Red",
+            template: "Burgundy",
+            kind: SwatchVariantKind::Unit,
+            fields: &[],
+            attributes: &["enum2str", "enum2str"],
+            groups: &["warm"],
+            doc: "",
+        }
+    );
+    let unique = &Swatch::VARIANT_INFO[3];
+    assert_eq!(unique.kind, SwatchVariantKind::Named);
+    assert_eq!(unique.fields, &["id", "label"]);
+    assert_eq!(unique.template, "Unique - {label}_{id}");
+    assert_eq!(Swatch::VARIANT_INFO[5].kind, SwatchVariantKind::Tuple);
+    assert_eq!(Swatch::VARIANT_INFO[5].fields, &["0", "1"]);
+}
+
+#[test]
+fn index_conversions() {
+    assert_eq!(Permission::from_index(2), Some(Permission::Execute));
+    assert_eq!(Permission::from_index(3), None);
+    assert_eq!(Permission::Write.index(), 1);
+    assert_eq!(Status::from_index(0), Some(Status::Pending));
+}
+
+#[derive(EnumStr, Debug, PartialEq)]
+#[enum2str(numeric)]
+enum Priority {
+    Low = 1,
+    High = 5,
+}
+
+impl Priority {
+    fn index(&self) -> u8 {
+        match self {
+            Priority::Low => 10,
+            Priority::High => 50,
+        }
+    }
+
+    fn iter() -> impl Iterator<Item = u8> {
+        [1, 5].into_iter()
+    }
+}
+
+#[test]
+fn own_ordinal_methods() {
+    assert_eq!("5".parse::<Priority>().unwrap(), Priority::High);
+    assert!("2".parse::<Priority>().is_err());
+    assert_eq!(Priority::Low.index(), 10);
+    assert_eq!(Priority::iter().count(), 2);
+}
+
+#[test]
+fn discriminant_conversions() {
+    assert_eq!(Channel::from_discriminant(4), Some(Channel::Alpha));
+    assert_eq!(Channel::from_discriminant(2), None);
+    assert_eq!(Channel::Red.discriminant(), 1u8);
+    assert_eq!(Opcode::Move.discriminant(), 0x88isize);
+    assert_eq!(Opcode::from_discriminant(0xf4), Some(Opcode::Halt));
+    assert_eq!(Opcode::Halt.index(), 2);
+}
+
+#[test]
+fn numeric_parse() {
+    assert_eq!("144".parse::<Opcode>().unwrap(), Opcode::Nop);
+    assert_eq!("nop".parse::<Opcode>().unwrap(), Opcode::Nop);
+    assert!("2".parse::<Opcode>().is_err());
+    assert_eq!("1".parse::<Permission>().unwrap(), Permission::Write);
+    assert!("3".parse::<Permission>().is_err());
+    assert!("1".parse::<Status>().is_err());
+}
+
+#[derive(EnumStr, Debug, PartialEq)]
+#[enum2str(kind = "WeekdayKind", iter)]
+enum Weekday {
+    Monday,
+    Tuesday,
+    #[enum2str(skip)]
+    Holiday,
+    Wednesday,
+}
+
+#[test]
+fn iterate() {
+    let days = Weekday::iter();
+    assert_eq!(days.len(), 3);
+    assert_eq!(
+        days.rev().collect::<Vec<_>>(),
+        vec![Weekday::Wednesday, Weekday::Tuesday, Weekday::Monday]
+    );
+    assert_eq!(Status::iter().count(), 4);
+}
+
+#[test]
+fn next_and_prev() {
+    assert_eq!(Weekday::Tuesday.next(), Some(Weekday::Wednesday));
+    assert_eq!(Weekday::Holiday.next(), Some(Weekday::Wednesday));
+    assert_eq!(Weekday::Wednesday.next(), None);
+    assert_eq!(Weekday::Wednesday.prev(), Some(Weekday::Tuesday));
+    assert_eq!(Weekday::Monday.prev(), None);
+    assert_eq!(Weekday::Wednesday.next_wrapping(), Some(Weekday::Monday));
+    assert_eq!(Weekday::Monday.prev_wrapping(), Some(Weekday::Wednesday));
+}
+
+#[derive(EnumStr)]
+#[enum2str(kind = "ParcelKind")]
+enum Parcel {
+    Generic(String),
+
+    #[enum2str("Color: {}. Shape: {}.")]
+    Complex(Color, Shape),
+}
+
+#[test]
+fn kind() {
+    let parcel = Parcel::Complex(Color::Green, Shape::Circle(3));
+    assert_eq!(parcel.kind(), ParcelKind::Complex);
+    assert_eq!(Parcel::Generic("x".to_string()).kind(), ParcelKind::Generic);
+    assert_eq!(ParcelKind::Complex.to_string(), "Complex");
+    assert_eq!(
+        "Generic".parse::<ParcelKind>().unwrap(),
+        ParcelKind::Generic
+    );
+    assert_eq!(ParcelKind::iter().count(), 2);
+
+    let kinds = std::collections::HashSet::from([parcel.kind(), ParcelKind::Complex]);
+    assert_eq!(kinds.len(), 1);
+}
+
+#[test]
+fn kind_keeps_unit_strings() {
+    assert_eq!(Status::InProgress.kind(), StatusKind::InProgress);
+    assert_eq!(StatusKind::InProgress.as_str(), "In Progress");
+    assert_eq!(WeekdayKind::iter().count(), 3);
+}
+
+#[derive(EnumStr, Debug, PartialEq)]
+#[enum2str(props(required(code)))]
+enum Response {
+    #[enum2str("OK", prop(code = 200, cacheable = true))]
+    Ok,
+    #[enum2str(
+        "Not Found",
+        prop(code = 404, cacheable = true, hint = "check the path")
+    )]
+    NotFound,
+    #[enum2str(prop(code = 503, retry_after = 1.5))]
+    Unavailable,
+    #[enum2str("Redirect to {}", prop(code = 302))]
+    Redirect(String),
+}
+
+#[test]
+fn typed_props() {
+    const CODE: u16 = Response::NotFound.prop_code() as u16;
+    assert_eq!(CODE, 404);
+    assert_eq!(Response::Redirect("/".to_string()).prop_code(), 302);
+    assert_eq!(Response::Ok.prop_cacheable(), Some(true));
+    assert_eq!(Response::Unavailable.prop_cacheable(), None);
+    assert_eq!(Response::NotFound.prop_hint(), Some("check the path"));
+    assert_eq!(Response::Unavailable.prop_retry_after(), Some(1.5));
+}
+
+#[test]
+fn get_prop() {
+    assert_eq!(
+        Response::Ok.get_prop("code"),
+        Some(ResponsePropValue::Int(200))
+    );
+    assert_eq!(
+        Response::NotFound.get_prop("hint"),
+        Some(ResponsePropValue::Str("check the path"))
+    );
+    assert_eq!(
+        Response::Unavailable.get_prop("retry_after"),
+        Some(ResponsePropValue::Float(1.5))
+    );
+    assert_eq!(Response::Ok.get_prop("hint"), None);
+    assert_eq!(Response::Ok.get_prop("missing"), None);
+    assert_eq!(Response::NotFound.to_string(), "Not Found");
+}
+
+#[derive(EnumStr)]
+#[enum2str(props(required = "unit, scale"))]
+enum Quantity {
+    #[enum2str(prop(unit = "m", scale = 1))]
+    Length,
+    #[enum2str(prop(unit = "ms", scale = 1000))]
+    Duration,
+}
+
+#[test]
+fn required_props_list() {
+    assert_eq!(Quantity::Length.prop_unit(), "m");
+    assert_eq!(Quantity::Duration.prop_scale(), 1000);
+    assert_eq!(Quantity::Duration.to_string(), "Duration");
+}
+
+#[test]
+fn groups() {
+    assert!(Status::Done.in_group("terminal"));
+    assert!(!Status::Pending.in_group("terminal"));
+    #[allow(deprecated)]
+    let finished = Status::Finished;
+    assert_eq!(finished.groups(), &["terminal", "legacy"]);
+    assert!(Swatch::Red.in_group("warm"));
+    assert!(Swatch::Green.groups().is_empty());
+}
+
+#[test]
+fn variants_in_group() {
+    assert_eq!(
+        Status::variants_in_group("active"),
+        &[Status::Pending, Status::InProgress]
+    );
+    assert_eq!(Status::variants_in_group("legacy").len(), 1);
+    assert!(Status::variants_in_group("unknown").is_empty());
+    assert_eq!(
+        StatusKind::variants_in_group("terminal"),
+        &[
+            #[allow(deprecated)]
+            StatusKind::Finished,
+            StatusKind::Done
+        ]
+    );
+}
+
+#[derive(EnumStr, Debug, PartialEq)]
+#[enum2str(reprs(ui, wire, short_code), default_repr = "ui")]
+enum Paint {
+    #[enum2str(repr(ui = "Burgundy red", wire = "RED", short_code = "R"))]
+    Red,
+    #[enum2str(repr(wire = "GRN", short_code = "G"))]
+    Green,
+}
+
+#[allow(dead_code)]
+#[derive(EnumStr)]
+enum Reading {
+    #[enum2str("Custom Color", locale(en = "Custom Color", de = "Eigene Farbe"))]
+    Custom,
+    #[enum2str("Temperature: {}", locale(de = "Temperatur: {}"))]
+    Temperature(i8),
+    #[enum2str(
+        "{count} of {total} done",
+        locale(de = "Von {total} sind {count} erledigt")
+    )]
+    Progress {
+        count: u8,
+        total: u8,
+    },
+    #[enum2str("Material", locale(de = "Stoff"))]
+    Material(Color),
+    Idle,
+}
+
+#[test]
+fn locale_display() {
+    assert_eq!(Reading::Custom.display_in("de").to_string(), "Eigene Farbe");
+    assert_eq!(Reading::Custom.display_in("en").to_string(), "Custom Color");
+    assert_eq!(Reading::Custom.display_in("fr").to_string(), "Custom Color");
+    assert_eq!(
+        Reading::Temperature(-4).display_in("de").to_string(),
+        "Temperatur: -4"
+    );
+    assert_eq!(
+        Reading::Progress { count: 2, total: 5 }
+            .display_in("de")
+            .to_string(),
+        "Von 5 sind 2 erledigt"
+    );
+    assert_eq!(
+        Reading::Progress { count: 2, total: 5 }
+            .display_in("fr")
+            .to_string(),
+        "2 of 5 done"
+    );
+    assert_eq!(
+        Reading::Material(Color::Green).display_in("de").to_string(),
+        "Stoff"
+    );
+    assert_eq!(
+        Reading::Material(Color::Green).display_in("fr").to_string(),
+        "Material"
+    );
+    assert_eq!(Reading::Idle.display_in("de").to_string(), "Idle");
+}
+
+#[test]
+fn reprs_to_str() {
+    assert_eq!(Paint::Red.to_str_as(PaintRepr::Wire), "RED");
+    assert_eq!(Paint::Green.to_str_as(PaintRepr::ShortCode), "G");
+    assert_eq!(Paint::Green.to_str_as(PaintRepr::Ui), "Green");
+    assert_eq!(Paint::repr_strings(PaintRepr::Wire), &["RED", "GRN"]);
+}
+
+#[test]
+fn reprs_from_str() {
+    assert_eq!(
+        Paint::from_str_as(PaintRepr::Wire, "GRN").unwrap(),
+        Paint::Green
+    );
+    assert_eq!(
+        Paint::from_str_as(PaintRepr::ShortCode, "R").unwrap(),
+        Paint::Red
+    );
+    assert_eq!(
+        Paint::from_str_as(PaintRepr::Wire, "Green").unwrap_err(),
+        "Invalid Paint Wire representation: Green"
+    );
+}
+
+#[test]
+fn default_repr_display() {
+    assert_eq!(Paint::Red.to_string(), "Burgundy red");
+    assert_eq!("Burgundy red".parse::<Paint>().unwrap(), Paint::Red);
+    assert_eq!(Paint::Green.as_str(), "Green");
+}
+
+#[test]
+fn ffi_as_cstr() {
+    let name = unsafe { std::ffi::CStr::from_ptr(channel_as_cstr(4)) };
+    assert_eq!(name, c"Alpha channel");
+    assert_eq!(
+        unsafe { std::ffi::CStr::from_ptr(channel_as_cstr(Channel::Red as u8)) },
+        c"Red"
+    );
+    assert!(channel_as_cstr(2).is_null());
+}
+
+#[cfg(feature = "log")]
+#[test]
+fn log_to_value() {
+    use log::kv::ToValue;
+
+    let status = Status::InProgress;
+    assert_eq!(status.to_value().to_borrowed_str(), Some("In Progress"));
+
+    let shape = Shape::Circle(2);
+    assert_eq!(shape.to_value().to_string(), "Circle with radius: 2");
+}
+
+#[cfg(feature = "log")]
+#[test]
+fn log_source() {
+    use log::kv::{Error, Key, Source, Value, VisitSource};
+
+    struct Collect(Vec<(String, String)>);
+
+    impl<'kvs> VisitSource<'kvs> for Collect {
+        fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), Error> {
+            self.0.push((key.to_string(), value.to_string()));
+            Ok(())
+        }
+    }
+
+    let mut collect = Collect(Vec::new());
+    let color = Color::Unique {
+        id: 3,
+        label: "unique_color".to_string(),
+    };
+    color.visit(&mut collect).unwrap();
+    assert_eq!(
+        collect.0,
+        vec![
+            ("template".to_string(), "Unique - {label}_{id}".to_string()),
+            ("id".to_string(), "3".to_string()),
+            ("label".to_string(), "unique_color".to_string()),
+        ]
+    );
+
+    let mut collect = Collect(Vec::new());
+    Object::Complex(Color::Green, Shape::Circle(2))
+        .visit(&mut collect)
+        .unwrap();
+    assert_eq!(
+        collect.0,
+        vec![
+            ("template".to_string(), "Color: {}. Shape: {}.".to_string()),
+            ("0".to_string(), "Green".to_string()),
+            ("1".to_string(), "Circle with radius: 2".to_string()),
+        ]
+    );
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn tracing_value() {
+    let value: &'static str = Status::InProgress.tracing_value();
+    assert_eq!(value, "In Progress");
+    assert_eq!(
+        format!("{:?}", Shape::Circle(2).tracing_value()),
+        "Circle with radius: 2"
+    );
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn record_tracing() {
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Empty, Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    struct Collect(Arc<Mutex<Vec<(String, String)>>>);
+
+    impl Visit for Collect {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            let value = format!("{:?}", value);
+            self.0
+                .lock()
+                .unwrap()
+                .push((field.name().to_string(), value));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            let value = value.to_string();
+            self.0
+                .lock()
+                .unwrap()
+                .push((field.name().to_string(), value));
+        }
+    }
+
+    struct Recorder(Arc<Mutex<Vec<(String, String)>>>);
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, values: &Record<'_>) {
+            values.record(&mut Collect(self.0.clone()));
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event<'_>) {}
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    let fields = Arc::new(Mutex::new(Vec::new()));
+    tracing::subscriber::with_default(Recorder(fields.clone()), || {
+        let span = tracing::info_span!("paint", template = Empty, id = Empty, label = Empty);
+        Color::Unique {
+            id: 3,
+            label: "unique_color".to_string(),
+        }
+        .record_tracing(&span);
+        Status::InProgress.record_tracing(&span);
+    });
+    assert_eq!(
+        *fields.lock().unwrap(),
+        vec![
+            ("template".to_string(), "Unique - {label}_{id}".to_string()),
+            ("id".to_string(), "3".to_string()),
+            ("label".to_string(), "unique_color".to_string()),
+            ("template".to_string(), "In Progress".to_string()),
+        ]
+    );
+}
+
+#[cfg(feature = "try_from_string")]
+#[test]
+fn test_try_from_str_and_cow() {
+    use std::{borrow::Cow, convert::TryFrom};
+
+    assert_eq!(Status::try_from("In Progress").unwrap(), Status::InProgress);
+    assert_eq!(
+        Status::try_from(Cow::Borrowed("Pending")).unwrap(),
+        Status::Pending
+    );
+    assert_eq!(
+        Status::try_from(Cow::Owned("Done".to_string())).unwrap(),
+        Status::Done
+    );
+    assert_eq!(
+        Casing::try_from("Custom Color").err().unwrap(),
+        "Ambiguous string representation. The following strings are used by multiple variants: 'Custom Color' (used by Spaced, Repeated)"
+    );
+}
+
+#[cfg(feature = "try_from_string")]
+#[test]
+fn test_try_from_mixed_enum() {
+    use std::convert::TryFrom;
+
+    assert_eq!(Color::try_from("Burgundy").unwrap(), Color::Red);
+    assert_eq!(Color::try_from("Green".to_string()).unwrap(), Color::Green);
+    assert!(Color::try_from("Custom Color").is_err());
+}
+
+#[cfg(feature = "fluent")]
+#[test]
+fn fluent_ids_and_source() {
+    #[derive(EnumStr)]
+    #[enum2str(fluent_prefix = "app-reading")]
+    enum Reading {
+        /// A sensor reading
+        #[enum2str("Temperature: {} at {}")]
+        Temperature(i8, String),
+        HttpError,
+    }
+
+    assert_eq!(Color::Green.fluent_id(), "color-green");
+    assert_eq!(Shape::Circle(2).fluent_id(), "shape-circle");
+    assert_eq!(Reading::HttpError.fluent_id(), "app-reading-http-error");
+    assert_eq!(
+        Reading::Temperature(21, "noon".to_string()).fluent_id(),
+        "app-reading-temperature"
+    );
+    assert_eq!(
+        Reading::fluent_source(),
+        "# A sensor reading\napp-reading-temperature = Temperature: { $arg0 } at { $arg1 }\napp-reading-http-error = HttpError\n"
+    );
+    assert_eq!(
+        Color::fluent_source(),
+        "color-green = Green\ncolor-red = Burgundy\ncolor-blue = Blue\ncolor-custom = Custom Color\ncolor-unique = Unique - { $label }_{ $id }\n"
+    );
+}
+
+#[cfg(feature = "fluent")]
+#[test]
+fn fluent_localize() {
+    use fluent_bundle::{FluentBundle, FluentResource};
+
+    let resource = FluentResource::try_new(
+        "color-red = Burgund\ncolor-unique = Einzigartig - { $label } ({ $id })\nshape-circle = Kreis mit Radius { $arg0 }\n"
+            .to_string(),
+    )
+    .unwrap();
+    let mut bundle = FluentBundle::new(vec!["de".parse().unwrap()]);
+    bundle.set_use_isolating(false);
+    bundle.add_resource(resource).unwrap();
+
+    assert_eq!(Color::Red.localize(&bundle), "Burgund");
+    assert_eq!(Color::Green.localize(&bundle), "Green");
+    assert_eq!(
+        Color::Unique {
+            label: "blau".to_string(),
+            id: 3
+        }
+        .localize(&bundle),
+        "Einzigartig - blau (3)"
+    );
+    assert_eq!(Shape::Circle(2).localize(&bundle), "Kreis mit Radius 2");
+
+    let default = FluentResource::try_new(Color::fluent_source().to_string()).unwrap();
+    let mut bundle = FluentBundle::new(vec!["en".parse().unwrap()]);
+    bundle.set_use_isolating(false);
+    bundle.add_resource(default).unwrap();
+    assert_eq!(
+        Color::Unique {
+            label: "sky".to_string(),
+            id: 7
+        }
+        .localize(&bundle),
+        Color::Unique {
+            label: "sky".to_string(),
+            id: 7
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn gettext_entries() {
+    assert_eq!(
+        Swatch::gettext_entries()[..2],
+        [("Swatch::Green", "Green"), ("Swatch::Red", "Burgundy")]
+    );
+    assert_eq!(
+        Swatch::gettext_entries()[3],
+        ("Swatch::Unique", "Unique - {label}_{id}")
+    );
+}
+
+#[test]
+fn gettext_pot() {
+    #[derive(EnumStr)]
+    #[enum2str(gettext)]
+    enum Measure {
+        #[enum2str("Circle with radius: {}")]
+        Circle(u8),
+    }
+
+    #[derive(EnumStr)]
+    #[enum2str(gettext)]
+    enum Quote {
+        #[enum2str("Say \"{}\"\n")]
+        Say(String),
+    }
+
+    let _ = (Measure::Circle(0), Quote::Say(String::new()));
+    let pot = enum2str::gettext_pot!(Measure, Quote);
+    assert_eq!(
+        pot,
+        r#"msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UTF-8\n"
+"Content-Transfer-Encoding: 8bit\n"
+
+msgctxt "Measure::Circle"
+msgid "Circle with radius: {}"
+msgstr ""
+
+msgctxt "Quote::Say"
+msgid "Say \"{}\"\n"
+msgstr ""
+"#
+    );
+}
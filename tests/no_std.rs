@@ -0,0 +1,122 @@
+#![no_std]
+
+extern crate std;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use enum2str::EnumStr;
+
+#[derive(EnumStr, Debug, PartialEq)]
+#[enum2str(set)]
+enum Direction {
+    North,
+
+    #[enum2str("South pole")]
+    South,
+}
+
+#[derive(EnumStr)]
+enum Reading {
+    #[enum2str("Temperature: {}")]
+    Temperature(i32),
+
+    #[enum2str("Wind {speed} from {heading}")]
+    Wind { speed: u8, heading: Direction },
+}
+
+#[derive(EnumStr, Debug, PartialEq, Clone, Copy)]
+#[enum2str(table)]
+enum Signal {
+    Stop,
+
+    #[enum2str("Go ahead")]
+    Go,
+}
+
+#[test]
+fn display() {
+    assert_eq!(std::format!("{}", Direction::South), "South pole");
+    assert_eq!(
+        std::format!(
+            "{}",
+            Reading::Wind {
+                speed: 4,
+                heading: Direction::North
+            }
+        ),
+        "Wind 4 from North"
+    );
+}
+
+#[test]
+fn from_str() {
+    assert_eq!("South pole".parse::<Direction>(), Ok(Direction::South));
+    assert!("West".parse::<Direction>().is_err());
+}
+
+#[cfg(not(feature = "alloc"))]
+#[test]
+fn static_parse_error() {
+    assert_eq!(
+        "West".parse::<Direction>(),
+        Err("Invalid Direction variant")
+    );
+}
+
+#[test]
+fn as_str() {
+    assert_eq!(Direction::North.as_str(), "North");
+    assert_eq!(Direction::South.as_str(), "South pole");
+}
+
+#[test]
+fn bytes() {
+    assert_eq!(Direction::South.as_bytes(), b"South pole");
+    assert_eq!(Direction::from_bytes(b"North"), Ok(Direction::North));
+}
+
+#[test]
+fn template() {
+    assert_eq!(Reading::Temperature(3).template(), "Temperature: {}");
+    assert_eq!(Direction::South.template(), "South pole");
+}
+
+#[test]
+fn variant_names() {
+    assert_eq!(Direction::VARIANT_NAMES, ["North", "South"]);
+    assert_eq!(Direction::variant_names(), ["North", "South"]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn arguments() {
+    assert_eq!(Reading::Temperature(3).arguments(), ["3"]);
+}
+
+#[cfg(feature = "try_from_string")]
+#[test]
+fn try_from_str() {
+    use core::convert::TryFrom;
+
+    assert_eq!(Direction::try_from("South pole"), Ok(Direction::South));
+}
+
+#[test]
+fn table_lookup() {
+    assert_eq!(std::format!("{}", Signal::Go), "Go ahead");
+    assert_eq!(Signal::Stop.as_str(), "Stop");
+    assert_eq!(Signal::Go.as_bytes(), b"Go ahead");
+    assert_eq!("Go ahead".parse::<Signal>(), Ok(Signal::Go));
+}
+
+#[test]
+fn set() {
+    let set = "South pole,North".parse::<DirectionSet>().unwrap();
+    assert_eq!(set, DirectionSet::all());
+    assert_eq!(std::format!("{}", set), "North,South pole");
+    assert_eq!(
+        std::format!("{}", set - DirectionSet::from(Direction::North)),
+        "South pole"
+    );
+}
@@ -0,0 +1,10 @@
+use enum2str::EnumStr;
+
+#[derive(EnumStr, Clone, Copy)]
+#[enum2str(reprs(ui, UI))]
+enum Light {
+    Red,
+    Green,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enum2str::EnumStr;
+
+#[derive(EnumStr, Clone, Copy)]
+#[enum2str(reprs(__))]
+enum Light {
+    Red,
+    Green,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enum2str::EnumStr;
+
+#[derive(EnumStr, Clone, Copy)]
+#[enum2str(set)]
+enum Flag {
+    On,
+    #[enum2str("")]
+    Unset,
+}
+
+fn main() {}
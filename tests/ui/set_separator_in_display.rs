@@ -0,0 +1,11 @@
+use enum2str::EnumStr;
+
+#[derive(EnumStr, Clone, Copy)]
+#[enum2str(set)]
+enum Mode {
+    Read,
+    #[enum2str("read,write")]
+    ReadWrite,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+extern crate alloc;
+use enum2str::EnumStr;
+
+#[derive(EnumStr)]
+#[enum2str(sql)]
+enum Reading {
+    Temperature(i8),
+}
+
+fn main() {}